@@ -1,7 +1,5 @@
 use proc_macro2::{Span, TokenStream};
 use proc_macro_error::{Diagnostic, Level};
-use syn::Fields;
-use syn::spanned::Spanned;
 
 #[must_use]
 #[forbid(unused_must_use)]
@@ -11,7 +9,6 @@ pub(crate) enum ErrorMessages<'tokens_lf> {
         found: TokenStream,
         span: Span,
     },
-    StructFieldsMustBeNamed { fields: Fields },
 }
 
 impl<'tokens_lf> ErrorMessages<'tokens_lf> {
@@ -32,13 +29,6 @@ impl<'tokens_lf> ErrorMessages<'tokens_lf> {
                     format!("Expected {expected}, but found {}.", found.to_string()),
                 )
             }
-            ErrorMessages::StructFieldsMustBeNamed { fields } => {
-                Diagnostic::spanned(
-                    fields.span(),
-                    Level::Error,
-                    "WebStorage macro targets structs with NAMED fields, but this has unnamed fields.".to_string(),
-                )
-            }
         }
     }
 }
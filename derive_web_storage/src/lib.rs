@@ -17,9 +17,14 @@ use core::mem;
 use proc_macro::{TokenStream, TokenTree};
 use proc_macro2::{Group, Span};
 use proc_macro_error::{Diagnostic, Level};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{parse_macro_input, Data, DeriveInput, Expr, Lit, LitStr, Meta, Visibility};
+use syn::{
+    parse_macro_input, Data, DeriveInput, Expr, ExprLit, GenericParam, Lit, LitStr, Meta, Token,
+    Visibility,
+};
 
 pub(crate) mod error_messages;
 
@@ -50,7 +55,27 @@ pub(crate) mod error_messages;
 ///     // Optional: This changes the default storage used in every StorageData.
 ///     // Default: The one you set in the features when importing this crate; If you didn't set
 ///     //          a default Storage, LocalStorage will be used.
-///     StorageKind(Local)
+///     StorageKind(Local),
+///     // Optional: This generates the struct 'StorageSnapshot', holding owned copies of every
+///     // value and deriving Clone, Serialize and Deserialize, along with 'Storage::snapshot()',
+///     // which creates it; every value must implement Clone. The name of the struct can be set
+///     // as in 'DeriveSnapshot(SettingsSnapshot)'.
+///     DeriveSnapshot,
+///     // Optional: This generates the struct 'StorageBuilder' along with 'Storage::builder()',
+///     // which creates it, and whose methods 'with_<field>_storage' and 'with_<field>_default'
+///     // override the storage kind and default value of a field before calling 'build()', such
+///     // as 'Storage::builder().with_visited_times_storage(StorageKind::Session).build()'. The
+///     // name of the struct can be set as in 'DeriveBuilder(SettingsBuilder)'.
+///     DeriveBuilder,
+///     // Optional: This implements PartialEq for 'Storage', comparing the value of every field,
+///     // which reads them from the Storage if they weren't read yet; every value must implement
+///     // PartialEq.
+///     DerivePartialEq,
+///     // Optional: This generates 'Storage::merge_from(&other)', copying the value of every field
+///     // of another instance, such as a preset, and 'Storage::merge_changed_only(&other)', only
+///     // copying those differing from their default value; copied values are saved on the next
+///     // save or drop, and every value must implement Clone and PartialEq.
+///     DeriveMerge
 /// )]
 /// pub struct Storage {
 ///     // It isn't necessary to specify the default value for visited_times as 'usize' implements
@@ -68,12 +93,23 @@ pub(crate) mod error_messages;
 ///         preferred_color: "Blue".to_string()
 ///     })]
 ///     user_info: UserInfo,
+///
+///     // Defaults can also be taken from a function, by giving its path either as is or as a
+///     // string, such as in serde's 'default = "..."'.
+///     #[default = UserInfo::anonymous]
+///     last_user_info: UserInfo,
 /// }
 ///
 /// struct UserInfo{
 ///     name:String, preferred_color: String,
 /// }
 ///
+/// impl UserInfo{
+///     fn anonymous() -> Self {
+///         UserInfo { name: "Anonymous".to_string(), preferred_color: "Red".to_string() }
+///     }
+/// }
+///
 /// let mut storage = Storage::new();
 ///
 /// // Increment visited times.
@@ -85,6 +121,164 @@ pub(crate) mod error_messages;
 ///
 /// // Storage is saved here when dropped, you aren't required to manually save it.
 /// ```
+///
+/// The generated struct implements [Default] through its ``new`` function, so it must not derive
+/// [Default] by itself.
+///
+/// Besides ``new``, the struct can be created through ``new_with_prefix``, which prepends a
+/// prefix decided at runtime, such as the id of the logged user, to the key of every field, in
+/// front of the one given to ``Prepend_keys_with``, so ``Storage::new_with_prefix("42::")``
+/// stores ``visited_times`` under ``42::USER::_::ALT::_visited_times``.
+///
+/// The prefix given to ``Prepend_keys_with`` is followed right away by the name of every field,
+/// unless a separator is given through ``KeySeparator``, such as in
+/// ``#[WebStorage(Prepend_keys_with(tenant), KeySeparator("/"))]``, which stores
+/// ``visited_times`` under ``tenant/visitedTimes``.
+///
+/// Fields can expire through ``ExpiresAfter``, taking the milliseconds after which the stored
+/// value is removed, see ``StorageData::with_ttl``, such as for a cached hint that must not
+/// outlive the session it came from, while the rest of fields persist indefinitely:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage]
+/// pub struct Auth {
+///     // Removed an hour after being saved.
+///     #[ExpiresAfter(3600000)]
+///     last_login_hint: Option<String>,
+///     remember_me: bool,
+/// }
+/// ```
+///
+/// Every glue can be reset to its default value through a function named ``reset_`` followed by
+/// the field's name, such as ``reset_visited_times``, while ``reset_all`` resets every glue.
+///
+/// Keys are the camelCased names of the fields, unless another case is given through ``KeyCase``,
+/// being either ``Camel``, ``Pascal``, ``Snake``, ``Kebab`` or ``Verbatim``, which keeps the
+/// names of the fields as they are, such as in ``#[WebStorage(KeyCase(Verbatim))]``, storing
+/// ``visited_times`` under ``visited_times``. When the ``verbatim_keys`` feature is enabled, keys
+/// are verbatim unless ``KeyCase`` tells otherwise.
+///
+/// Keys produced by other frameworks might keep acronyms uppercased, such as ``userID`` rather
+/// than ``userId``, which can be matched through ``AcronymBoundaries``, uppercasing every word of
+/// the camelCased key matching one of the indicated acronyms:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(AcronymBoundaries("ID", "URL"))]
+/// pub struct Session {
+///     // Stored under the key 'userID'.
+///     user_id: u64,
+///     // Stored under the key 'avatarURL'.
+///     avatar_url: String,
+/// }
+/// ```
+///
+/// Fields whose type is an [Option] default to ``None``, and besides, they get a function named
+/// after the field followed by ``_or``, getting the contained value or the indicated fallback, and
+/// another followed by ``_is_present``, telling whether there is a value:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(Prepend_keys_with(USER::))]
+/// pub struct User {
+///     nickname: Option<String>,
+/// }
+///
+/// let user = User::new();
+/// if !user.nickname_is_present() {
+///     println!("Hello {}", user.nickname_or(&String::from("guest")));
+/// }
+/// ```
+///
+/// Structs with type parameters are supported too, where every type parameter is required to
+/// implement ``serde::Serialize`` and ``serde::Deserialize``, and fields relying on [Default] for
+/// their default values require their type parameters to implement it, while lifetimes and const
+/// parameters are rejected:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(Prepend_keys_with(CACHE::))]
+/// pub struct Cache<T: Default> {
+///     last_value: T,
+/// }
+///
+/// let mut cache = Cache::<u32>::new();
+/// *cache.last_value = 5;
+/// ```
+///
+/// Tuple structs are supported too, in which case every key is made of the prefix followed by
+/// ``field`` and the index of the field, such as ``field0`` and ``field1``, and every glue can
+/// be reached either through its index or through an accessor named ``field_`` plus its index,
+/// and reset through a function named ``reset_field_`` plus its index:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(Prepend_keys_with(COUNTERS::))]
+/// pub struct Counters(usize, usize);
+///
+/// let mut counters = Counters::new();
+///
+/// // Increments the value stored under the key 'COUNTERS::field0'.
+/// **counters.field_0() += 1;
+/// // Increments the value stored under the key 'COUNTERS::field1'.
+/// *counters.1 += 1;
+/// ```
+///
+/// Fields are as visible as the struct, unless ``EncapsulateFields`` is given, which makes them
+/// private and generates a getter and a mutable getter for each of them instead, named after
+/// the field, or after ``field_`` plus its index for tuple structs, and followed by ``_mut`` for
+/// the mutable getter, so library authors can change the layout of the struct without changing
+/// its API:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(EncapsulateFields)]
+/// pub struct Settings {
+///     volume: u8,
+/// }
+///
+/// let mut settings = Settings::new();
+/// **settings.volume_mut() = 50;
+/// assert_eq!(**settings.volume(), 50);
+/// ```
+///
+/// Large settings trees can be split into many structs, where a field marked as ``#[Flatten]``
+/// holds another struct using this macro rather than a glue, which is created, saved, cleared,
+/// reset and reloaded along with this struct. Its keys are made of the prefix given to this
+/// struct's ``new_with_prefix``, followed by the prefix given to this struct's
+/// ``Prepend_keys_with`` and ``KeySeparator``, and then by its own key, so the name of the
+/// flattened field isn't part of it, just like in serde's ``flatten``:
+///
+/// ```rust no_compile
+/// use derive_web_storage::WebStorage;
+///
+/// #[WebStorage(Prepend_keys_with(VIDEO::))]
+/// pub struct VideoSettings {
+///     resolution: u32,
+/// }
+///
+/// #[WebStorage(Prepend_keys_with(SETTINGS::))]
+/// pub struct Settings {
+///     // Stored under the key 'SETTINGS::VIDEO::resolution'.
+///     #[Flatten]
+///     video: VideoSettings,
+///     // Stored under the key 'SETTINGS::volume'.
+///     volume: u8,
+/// }
+///
+/// let mut settings = Settings::new();
+/// *settings.video.resolution = 1080;
+/// ```
+///
+/// As flattened structs are created through their ``new_with_prefix``, structs with flattened
+/// fields can't be created on const contexts.
 #[allow(non_snake_case)]
 #[proc_macro_error::proc_macro_error]
 #[proc_macro_attribute]
@@ -93,8 +287,8 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
         vis,
         attrs: _attrs,
         ident: struct_ident,
+        generics,
         data,
-        ..
     } = parse_macro_input!(input as DeriveInput);
     let struct_data = match data {
         Data::Struct(struct_data) => struct_data,
@@ -108,18 +302,46 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    let first_field_is_named = struct_data
+    let generics = if generics.params.is_empty() {
+        quote! {}
+    } else {
+        let mut generic_idents = Vec::new();
+        let mut where_predicates = Vec::new();
+        for param in &generics.params {
+            match param {
+                GenericParam::Type(type_param) => {
+                    let ident = &type_param.ident;
+                    let bounds = &type_param.bounds;
+                    generic_idents.push(ident.clone());
+                    if !bounds.is_empty() {
+                        where_predicates.push(quote! { #ident: #bounds });
+                    }
+                }
+                _ => ErrorMessages::ExpectedDifferent {
+                    expected: "a type parameter",
+                    span: param.span(),
+                    found: param.to_token_stream(),
+                }
+                .abort(),
+            }
+        }
+        if let Some(where_clause) = &generics.where_clause {
+            where_predicates.extend(where_clause.predicates.iter().map(ToTokens::to_token_stream));
+        }
+        quote! { generics: [#(#generic_idents),*] where [#(#where_predicates,)*], }
+    };
+
+    let fields_are_named = struct_data
         .fields
         .iter()
         .next()
         .map(|first_field| first_field.ident.is_some())
         .unwrap_or(true);
-    if !first_field_is_named {
-        ErrorMessages::StructFieldsMustBeNamed {
-            fields: struct_data.fields,
-        }
-        .abort();
-    }
+    let fields_kind = if fields_are_named {
+        quote! {}
+    } else {
+        quote! { fields: unnamed, }
+    };
 
     let attrs = separate_token_stream_by_commas(macro_attr)
         .into_iter()
@@ -130,7 +352,18 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
 
     let mut constructor_visibility = Some(vis.clone());
     let mut prepend = String::new();
+    let mut key_separator = String::new();
     let mut storage_kind = quote! {};
+    let mut snapshot = quote! {};
+    let mut builder = quote! {};
+    let mut partial_eq = quote! {};
+    let mut merge = quote! {};
+    let mut encapsulate_fields = false;
+    let mut acronyms = Vec::new();
+    let mut key_case = match cfg!(feature = "verbatim_keys") {
+        true => None,
+        false => Some(convert_case::Case::Camel),
+    };
 
     #[cfg(feature = "default_storage_local")]
     let mut storage_kind_for_doc = Lit::Str(LitStr::new("Local", Span::call_site()));
@@ -143,6 +376,18 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
             | "prepend_keys_with" => {
                 prepend = group_interior(contents).to_string().replace(" ", "");
             }
+            "keyseparator" | "key_separator" | "separator" => {
+                let contents = proc_macro2::TokenStream::from(group_interior(contents));
+                match syn::parse2::<LitStr>(contents.clone()) {
+                    Ok(separator) => key_separator = separator.value(),
+                    Err(_) => ErrorMessages::ExpectedDifferent {
+                        expected: "a separator written as a string literal",
+                        span: contents.span(),
+                        found: contents,
+                    }
+                    .abort(),
+                }
+            }
             "constructorvisibility"
             | "constructor_visibility"
             | "constructorvis"
@@ -170,17 +415,175 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
                 storage_kind =
                     quote! { with storage kind ::storage_data::StorageKind:: #contents, };
             }
+            "derivesnapshot" | "derive_snapshot" | "snapshot" => {
+                let contents = proc_macro2::TokenStream::from(group_interior(contents));
+                let snapshot_ident = if contents.is_empty() {
+                    format_ident!("{struct_ident}Snapshot")
+                } else {
+                    match syn::parse2::<proc_macro2::Ident>(contents.clone()) {
+                        Ok(snapshot_ident) => snapshot_ident,
+                        Err(_) => ErrorMessages::ExpectedDifferent {
+                            expected: "the name of the snapshot struct",
+                            span: contents.span(),
+                            found: contents,
+                        }
+                        .abort(),
+                    }
+                };
+                snapshot = quote! { snapshot: #snapshot_ident, };
+            }
+            "derivebuilder" | "derive_builder" | "builder" => {
+                let contents = proc_macro2::TokenStream::from(group_interior(contents));
+                let builder_ident = if contents.is_empty() {
+                    format_ident!("{struct_ident}Builder")
+                } else {
+                    match syn::parse2::<proc_macro2::Ident>(contents.clone()) {
+                        Ok(builder_ident) => builder_ident,
+                        Err(_) => ErrorMessages::ExpectedDifferent {
+                            expected: "the name of the builder struct",
+                            span: contents.span(),
+                            found: contents,
+                        }
+                        .abort(),
+                    }
+                };
+                builder = quote! { builder: #builder_ident, };
+            }
+            "derivepartialeq" | "derive_partial_eq" | "partialeq" | "partial_eq" => {
+                partial_eq = quote! { partial eq: true, };
+            }
+            "derivemerge" | "derive_merge" | "merge" => {
+                merge = quote! { merge: true, };
+            }
+            "encapsulatefields" | "encapsulate_fields" | "encapsulate" => {
+                encapsulate_fields = true;
+            }
+            "keycase" | "key_case" | "case" => {
+                let contents = proc_macro2::TokenStream::from(group_interior(contents));
+                key_case = match contents.to_string().to_lowercase().trim() {
+                    "camel" | "camelcase" | "camel_case" => Some(convert_case::Case::Camel),
+                    "pascal" | "pascalcase" | "pascal_case" => Some(convert_case::Case::Pascal),
+                    "snake" | "snakecase" | "snake_case" => Some(convert_case::Case::Snake),
+                    "kebab" | "kebabcase" | "kebab_case" => Some(convert_case::Case::Kebab),
+                    "verbatim" => None,
+                    _ => ErrorMessages::ExpectedDifferent {
+                        expected: "either Camel, Pascal, Snake, Kebab or Verbatim",
+                        span: contents.span(),
+                        found: contents,
+                    }
+                    .abort(),
+                };
+            }
+            "acronymboundaries" | "acronym_boundaries" | "acronyms" => {
+                let contents = proc_macro2::TokenStream::from(group_interior(contents));
+                let parsed = Punctuated::<LitStr, Token![,]>::parse_terminated
+                    .parse2(contents.clone());
+                match parsed {
+                    Ok(parsed) => acronyms.extend(parsed.iter().map(LitStr::value)),
+                    Err(_) => ErrorMessages::ExpectedDifferent {
+                        expected: "acronyms written as string literals",
+                        span: contents.span(),
+                        found: contents,
+                    }
+                    .abort(),
+                }
+            }
             _ => {}
         }
     }
     let constructor_visibility = constructor_visibility.unwrap_or(vis.clone());
+    let field_visibility = if encapsulate_fields {
+        quote! { field visibility: pub(self), }
+    } else {
+        quote! {}
+    };
+    if !prepend.is_empty() {
+        prepend.push_str(&key_separator);
+    }
 
-    let fields_count = struct_data.fields.len();
+    let (flattened_fields, glue_fields): (Vec<_>, Vec<_>) = struct_data
+        .fields
+        .iter()
+        .enumerate()
+        .partition(|(_, field)| {
+            field.attrs.iter().any(|attr| {
+                attr.path().to_token_stream().to_string().to_lowercase().trim() == "flatten"
+            })
+        });
+    let fields_count = glue_fields.len();
+
+    let flattened = if flattened_fields.is_empty() {
+        quote! {}
+    } else {
+        let flattened_tokens = flattened_fields.iter().map(|(_, field)| {
+            let Some(variable_name) = field.ident.as_ref() else {
+                ErrorMessages::ExpectedDifferent {
+                    expected: "a named field, as fields of tuple structs can't be flattened",
+                    span: field.span(),
+                    found: field.to_token_stream(),
+                }
+                .abort()
+            };
+            let variable_type = &field.ty;
+            let variable_doc = field
+                .attrs
+                .iter()
+                .filter_map(extract_doc_comment)
+                .collect::<Vec<_>>()
+                .join("\n");
+            quote! {
+                #variable_name : #variable_type, named #prepend,
+                with documentation #variable_doc;
+            }
+        });
+        quote! { flattened: { #(#flattened_tokens)* }, }
+    };
 
     let mut fields_tokens = quote! {};
-    struct_data.fields.iter().for_each(|field| {
-        let variable_name = field.ident.as_ref().unwrap();
+    glue_fields.into_iter().for_each(|(index, field)| {
+        let (variable_name, accessor, field_name, reset) = match field.ident.as_ref() {
+            Some(ident) => (
+                ident.to_token_stream(),
+                quote! {},
+                ident.to_string(),
+                format_ident!("reset_{}", ident),
+            ),
+            None => {
+                let accessor = proc_macro2::Ident::new(&format!("field_{index}"), field.span());
+                (
+                    syn::Index::from(index).to_token_stream(),
+                    quote! { with accessor #accessor, },
+                    format!("field{index}"),
+                    format_ident!("reset_field_{index}"),
+                )
+            }
+        };
         let variable_type = &field.ty;
+        let method_prefix = match field.ident.as_ref() {
+            Some(ident) => format_ident!("{}", ident),
+            None => format_ident!("field_{index}"),
+        };
+        let (accessor, getters) = if encapsulate_fields {
+            let getter_mut = format_ident!("{method_prefix}_mut");
+            (quote! {}, quote! { with getters #method_prefix, #getter_mut, })
+        } else {
+            (accessor, quote! {})
+        };
+        let builder_methods = if builder.is_empty() {
+            quote! {}
+        } else {
+            let with_storage = format_ident!("with_{method_prefix}_storage");
+            let with_default = format_ident!("with_{method_prefix}_default");
+            quote! { with builder #with_storage, #with_default, }
+        };
+        let optional = match option_inner_type(variable_type) {
+            Some(inner_type) => {
+                let or = format_ident!("{method_prefix}_or");
+                let is_present = format_ident!("{method_prefix}_is_present");
+                quote! { with optional #or, #is_present, #inner_type, }
+            }
+            None => quote! {},
+        };
         let variable_doc = field
             .attrs
             .iter()
@@ -212,8 +615,25 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
 
         let mut field_storage = None;
         let mut field_storage_kind_for_doc = None;
+        let mut field_ttl_ms = quote! {};
         for (ident, contents) in separated_attributes {
             match ident.to_string().to_lowercase().trim() {
+                "ttl_ms" | "ttlms" | "ttl" => {
+                    field_ttl_ms = quote! { with ttl ms #contents, };
+                }
+                "expires_after" | "expiresafter" => {
+                    let ttl_ms = match syn::parse2::<Lit>(contents.clone()) {
+                        Ok(Lit::Int(millis)) => quote! { #millis },
+                        Ok(Lit::Float(millis)) => quote! { #millis as u64 },
+                        _ => ErrorMessages::ExpectedDifferent {
+                            expected: "an integer or float literal of milliseconds",
+                            span: contents.span(),
+                            found: contents,
+                        }
+                        .abort(),
+                    };
+                    field_ttl_ms = quote! { with ttl ms #ttl_ms, };
+                }
                 "storage_kind" | "storagekind" | "storage" => {
                     field_storage_kind_for_doc = Some(Lit::Str(LitStr::new(
                         &*contents.to_string(),
@@ -230,10 +650,11 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
         let field_storage_kind_for_doc =
             field_storage_kind_for_doc.unwrap_or(storage_kind_for_doc.clone());
 
-        let web_name = format!(
-            "{prepend}{}",
-            variable_name.to_string().to_case(convert_case::Case::Camel)
-        );
+        let cased_field_name = match key_case {
+            Some(key_case) => field_name.to_case(key_case),
+            None => field_name.clone(),
+        };
+        let web_name = format!("{prepend}{}", uppercase_acronyms(&cased_field_name, &acronyms));
         let default_field = field
             .attrs
             .iter()
@@ -247,7 +668,22 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
                         unreachable!()
                     }
                     Meta::List(list) => list.tokens.clone(),
-                    Meta::NameValue(name_value) => name_value.value.to_token_stream(),
+                    Meta::NameValue(name_value) => match &name_value.value {
+                        Expr::Path(path) => quote! { #path() },
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(path),
+                            ..
+                        }) => match path.parse::<syn::Path>() {
+                            Ok(path) => quote! { #path() },
+                            Err(_) => ErrorMessages::ExpectedDifferent {
+                                expected: "a path to a function",
+                                span: path.span(),
+                                found: path.to_token_stream(),
+                            }
+                            .abort(),
+                        },
+                        value => value.to_token_stream(),
+                    },
                 }
             }
         };
@@ -255,10 +691,16 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
             #fields_tokens
             {
                 variable #variable_name,
+                #accessor
+                #getters
+                with reset #reset,
+                #builder_methods
+                #optional
                 type #variable_type,
                 named #web_name,
                 default { #default },
                 #field_storage
+                #field_ttl_ms
                 with documentation #variable_doc,
                 storage kind for doc #field_storage_kind_for_doc,
             }
@@ -269,7 +711,15 @@ pub fn WebStorage(macro_attr: TokenStream, input: TokenStream) -> TokenStream {
         ::storage_data::define_storage!{
             #vis #struct_ident with storage data {
                 len: #fields_count,
+                #fields_kind
+                #snapshot
+                #builder
+                #partial_eq
+                #merge
+                #generics
                 constructor visibility: #constructor_visibility,
+                #field_visibility
+                #flattened
                 #fields_tokens
             }
         }
@@ -314,7 +764,10 @@ fn ident_and_group(input: TokenStream) -> Option<(proc_macro2::Ident, proc_macro
         },
     }
     match token_iter.next() {
-        None => return None,
+        None => {
+            let ident = syn::parse::<proc_macro2::Ident>(TokenStream::from(ident)).unwrap();
+            return Some((ident, TokenStream::new()));
+        }
         Some(token) => match &token {
             proc_macro::TokenTree::Group(_) => {
                 group = TokenStream::from(token);
@@ -356,3 +809,46 @@ fn extract_doc_comment(attr: &syn::Attribute) -> Option<String> {
     }
     None
 }
+
+fn option_inner_type(field_type: &syn::Type) -> Option<&syn::Type> {
+    // Check if the type is a path ending in `Option`, such as `Option<T>` or
+    // `core::option::Option<T>`
+    let syn::Type::Path(type_path) = field_type else {
+        return None;
+    };
+    let last_segment = type_path.path.segments.last()?;
+    if last_segment.ident != "Option" {
+        return None;
+    }
+    // Get the only generic argument of `Option`
+    let syn::PathArguments::AngleBracketed(arguments) = &last_segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        syn::GenericArgument::Type(inner_type) if arguments.args.len() == 1 => Some(inner_type),
+        _ => None,
+    }
+}
+
+fn uppercase_acronyms(camel_case: &str, acronyms: &[String]) -> String {
+    // Split the key into its words, every word but the first starting by an uppercase letter,
+    // such as `userId` into `user` and `Id`
+    let mut words = Vec::<String>::new();
+    for char in camel_case.chars() {
+        match words.last_mut() {
+            Some(word) if !char.is_uppercase() => word.push(char),
+            _ => words.push(String::from(char)),
+        }
+    }
+    // Uppercase the words matching an acronym, such as `Id` into `ID`
+    words
+        .into_iter()
+        .map(|word| {
+            let uppercase_word = word.to_uppercase();
+            match acronyms.iter().any(|acronym| acronym.to_uppercase() == uppercase_word) {
+                true => uppercase_word,
+                false => word,
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,80 @@
+use crate::log_error::LogError;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+use wasm_bindgen::JsCast;
+use web_sys::js_sys;
+
+/// Maximum size of a cookie most web browsers accept, counting both its name and its value.
+const MAX_COOKIE_SIZE: usize = 4096;
+
+fn html_document() -> Result<web_sys::HtmlDocument, Box<dyn Error>> {
+    crate::window()
+        .map_log_possible_error(|_| "Could not get windows")?
+        .document()
+        .map_log_possible_error(|_| "Could not get document")?
+        .dyn_into::<web_sys::HtmlDocument>()
+        .map_log_possible_error(|_| "Document is not an HTML document")
+}
+
+fn encode(text: &str) -> String {
+    js_sys::encode_uri_component(text).into()
+}
+
+fn decode(text: &str) -> Result<String, Box<dyn Error>> {
+    js_sys::decode_uri_component(text)
+        .map(Into::into)
+        .map_log_possible_error(|err| format!("Could not decode cookie {text} due to {err:?}"))
+}
+
+/// Every cookie of the document as pairs of names and values, both of them still encoded.
+fn cookies() -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let cookies = html_document()?
+        .cookie()
+        .map_log_possible_error(|err| format!("Could not get cookies due to {err:?}"))?;
+    Ok(cookies
+        .split(';')
+        .filter_map(|cookie| {
+            let (name, value) = cookie.split_once('=')?;
+            Some((String::from(name.trim()), String::from(value.trim())))
+        })
+        .collect())
+}
+
+pub(crate) fn get_item(key: &str) -> Result<Option<String>, Box<dyn Error>> {
+    let key = encode(key);
+    cookies()?
+        .into_iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| decode(&value))
+        .transpose()
+}
+
+pub(crate) fn set_item(key: &str, value: &str, max_age_secs: u32) -> Result<(), Box<dyn Error>> {
+    let (key, value) = (encode(key), encode(value));
+    if key.len() + value.len() + 1 > MAX_COOKIE_SIZE {
+        return None::<()>.map_log_possible_error(|_| {
+            format!(
+                "Could not set cookie {key}, as its encoded value takes {} bytes, exceeding the \
+                 limit of {MAX_COOKIE_SIZE} bytes",
+                key.len() + value.len() + 1
+            )
+        });
+    }
+    html_document()?
+        .set_cookie(&format!("{key}={value}; max-age={max_age_secs}; path=/; SameSite=Lax"))
+        .map_log_possible_error(|err| format!("Could not set cookie {key} due to {err:?}"))
+}
+
+pub(crate) fn remove_item(key: &str) -> Result<(), Box<dyn Error>> {
+    let key = encode(key);
+    html_document()?
+        .set_cookie(&format!("{key}=; max-age=0; path=/; SameSite=Lax"))
+        .map_log_possible_error(|err| format!("Could not remove cookie {key} due to {err:?}"))
+}
+
+pub(crate) fn len() -> Result<usize, Box<dyn Error>> {
+    Ok(cookies()?.len())
+}
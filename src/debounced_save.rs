@@ -0,0 +1,144 @@
+use crate::item_store::ItemStore;
+use crate::log_error::LogError;
+use crate::StorageKind;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use core::cell::{Cell, RefCell};
+use core::error::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Serialized value waiting to be written, along with the storage and key to write it to.
+type PendingWrite = Option<(StorageKind, Option<web_sys::Storage>, String, String)>;
+
+/// Handle of the timer a pending write waits for, being either a timeout or an idle callback.
+#[derive(Clone, Copy)]
+enum Timer {
+    Timeout(i32),
+    Idle(u32),
+}
+
+/// Deferred writes of a glue's value, where every write scheduled replaces the previous one and
+/// postpones the timer, so bursts of writes are collapsed into a single one.
+pub(crate) struct DebouncedSave {
+    window: web_sys::Window,
+    millis: i32,
+    when_idle: bool,
+    pending_write: Rc<RefCell<PendingWrite>>,
+    pending_timeout: Rc<Cell<Option<Timer>>>,
+    on_timeout: Closure<dyn FnMut()>,
+}
+
+impl DebouncedSave {
+    /// Creates the deferred writes, waiting for the indicated milliseconds before writing.
+    pub(crate) fn new(millis: f64) -> Result<Self, Box<dyn Error>> {
+        Self::with_timer(millis, false)
+    }
+
+    /// Creates the deferred writes, waiting for the web browser to be idle before writing, or,
+    /// where ``requestIdleCallback`` isn't supported, for the next turn of the event loop.
+    pub(crate) fn when_idle() -> Result<Self, Box<dyn Error>> {
+        Self::with_timer(0.0, true)
+    }
+
+    fn with_timer(millis: f64, when_idle: bool) -> Result<Self, Box<dyn Error>> {
+        let window = crate::window().map_log_possible_error(|_| "Could not get windows")?;
+        let pending_write = Rc::new(RefCell::new(None));
+        let pending_timeout = Rc::new(Cell::new(None));
+        let on_timeout = {
+            let pending_write = pending_write.clone();
+            let pending_timeout = pending_timeout.clone();
+            Closure::<dyn FnMut()>::new(move || {
+                pending_timeout.set(None);
+                let _ = write(&pending_write);
+            })
+        };
+        Ok(Self {
+            window,
+            millis: millis as i32,
+            when_idle,
+            pending_write,
+            pending_timeout,
+            on_timeout,
+        })
+    }
+
+    /// Tells whether there is a write waiting for its timer.
+    pub(crate) fn is_pending(&self) -> bool {
+        self.pending_write.borrow().is_some()
+    }
+
+    /// Schedules the write of the serialized value, replacing any pending write.
+    pub(crate) fn schedule(
+        &self,
+        item_store: ItemStore<'_>,
+        key: &str,
+        serialized: String,
+    ) -> Result<(), Box<dyn Error>> {
+        let (storage_kind, storage_object) = item_store.into_owned();
+        self.pending_write
+            .replace(Some((storage_kind, storage_object, String::from(key), serialized)));
+        self.clear_timeout();
+        let on_timeout = self.on_timeout.as_ref().unchecked_ref();
+        let idle_callback = match self.when_idle {
+            true => self.window.request_idle_callback(on_timeout).ok(),
+            false => None,
+        };
+        let timeout = match idle_callback {
+            Some(idle_callback) => Ok(Timer::Idle(idle_callback)),
+            None => self
+                .window
+                .set_timeout_with_callback_and_timeout_and_arguments_0(on_timeout, self.millis)
+                .map(Timer::Timeout)
+                .map_log_possible_error(|err| format!("Could not set timeout due to {err:?}")),
+        };
+        match timeout {
+            Ok(timeout) => {
+                self.pending_timeout.set(Some(timeout));
+                Ok(())
+            }
+            Err(_) => write(&self.pending_write),
+        }
+    }
+
+    /// Writes the pending value right away, if any.
+    pub(crate) fn flush(&self) -> Result<(), Box<dyn Error>> {
+        self.clear_timeout();
+        write(&self.pending_write)
+    }
+
+    /// Discards the pending value, if any, without writing it.
+    pub(crate) fn cancel(&self) {
+        self.clear_timeout();
+        self.pending_write.take();
+    }
+
+    fn clear_timeout(&self) {
+        match self.pending_timeout.take() {
+            Some(Timer::Timeout(timeout)) => self.window.clear_timeout_with_handle(timeout),
+            Some(Timer::Idle(idle_callback)) => self.window.cancel_idle_callback(idle_callback),
+            None => {}
+        }
+    }
+}
+
+fn write(pending_write: &RefCell<PendingWrite>) -> Result<(), Box<dyn Error>> {
+    match pending_write.take() {
+        Some((storage_kind, storage_object, key, serialized)) => {
+            ItemStore::new(&storage_kind, storage_object.as_ref()).set_item(&key, || Ok(serialized))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Upon drop, the pending value is written, and the timer is cancelled, so it doesn't fire once
+/// this is freed.
+impl Drop for DebouncedSave {
+    /// Upon drop, the pending value is written, and the timer is cancelled, so it doesn't fire
+    /// once this is freed.
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,102 @@
+use crate::log_error::LogError;
+use crate::SerializedPayload;
+use alloc::boxed::Box;
+use alloc::format;
+use core::error::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::{Promise, Uint8Array};
+use web_sys::{IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+/// Waits until the request either succeeds or fails, as IndexedDB notifies it through events
+/// rather than through promises.
+async fn completion(request: &IdbRequest) -> Result<JsValue, Box<dyn Error>> {
+    let promise = Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    let completion = JsFuture::from(promise).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+    completion.map_log_possible_error(|err| format!("IndexedDB request failed due to {err:?}"))?;
+    request
+        .result()
+        .map_log_possible_error(|err| format!("Could not get IndexedDB result due to {err:?}"))
+}
+
+/// Opens the database holding the indicated object store, every store is kept in a database of
+/// its own named after it, so the store can be created the first time it's opened without
+/// having to know every store up front.
+async fn open(store: &'static str) -> Result<IdbDatabase, Box<dyn Error>> {
+    let request = crate::window()
+        .map_log_possible_error(|_| "Could not get windows")?
+        .indexed_db()
+        .map_log_possible_error(|err| format!("Could not get IndexedDB ({err:?})"))?
+        .map_log_possible_error(|_| "Could not get IndexedDB")?
+        .open_with_u32(store, 1)
+        .map_log_possible_error(|err| format!("Could not open IndexedDB {store} ({err:?})"))?;
+    let upgrade_request = request.clone();
+    let on_upgrade_needed = Closure::once(move || {
+        if let Ok(database) = upgrade_request.result() {
+            let _ = database.unchecked_into::<IdbDatabase>().create_object_store(store);
+        }
+    });
+    request.set_onupgradeneeded(Some(on_upgrade_needed.as_ref().unchecked_ref()));
+    let database = completion(&request).await;
+    request.set_onupgradeneeded(None);
+    Ok(database?.unchecked_into())
+}
+
+async fn object_store(
+    store: &'static str,
+    mode: IdbTransactionMode,
+) -> Result<IdbObjectStore, Box<dyn Error>> {
+    open(store)
+        .await?
+        .transaction_with_str_and_mode(store, mode)
+        .map_log_possible_error(|err| format!("Could not start IndexedDB transaction ({err:?})"))?
+        .object_store(store)
+        .map_log_possible_error(|err| format!("Could not get IndexedDB store {store} ({err:?})"))
+}
+
+pub(crate) async fn get_payload(
+    store: &'static str,
+    key: &str,
+) -> Result<Option<SerializedPayload>, Box<dyn Error>> {
+    let request = object_store(store, IdbTransactionMode::Readonly)
+        .await?
+        .get(&JsValue::from_str(key))
+        .map_log_possible_error(|err| format!("Could not get value for key {key} ({err:?})"))?;
+    let stored = completion(&request).await?;
+    if let Some(serialized) = stored.as_string() {
+        return Ok(Some(SerializedPayload::Text(serialized)));
+    }
+    Ok(stored.dyn_into::<Uint8Array>().ok().map(|bytes| SerializedPayload::Binary(bytes.to_vec())))
+}
+
+pub(crate) async fn set_payload(
+    store: &'static str,
+    key: &str,
+    payload: SerializedPayload,
+) -> Result<(), Box<dyn Error>> {
+    let stored = match payload {
+        SerializedPayload::Text(serialized) => JsValue::from_str(&serialized),
+        SerializedPayload::Binary(bytes) => Uint8Array::from(bytes.as_slice()).into(),
+    };
+    let request = object_store(store, IdbTransactionMode::Readwrite)
+        .await?
+        .put_with_key(&stored, &JsValue::from_str(key))
+        .map_log_possible_error(|err| format!("Could not set value for key {key} ({err:?})"))?;
+    completion(&request).await?;
+    Ok(())
+}
+
+pub(crate) async fn remove_item(store: &'static str, key: &str) -> Result<(), Box<dyn Error>> {
+    let request = object_store(store, IdbTransactionMode::Readwrite)
+        .await?
+        .delete(&JsValue::from_str(key))
+        .map_log_possible_error(|err| format!("Could not remove value of key {key} ({err:?})"))?;
+    completion(&request).await?;
+    Ok(())
+}
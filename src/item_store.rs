@@ -0,0 +1,99 @@
+use crate::log_error::LogError;
+use crate::{StorageError, StorageKind};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use core::error::Error;
+
+/// Storage a glue reads and writes its items from, being either the one of its storage kind, or
+/// the Storage object given through [crate::StorageData::with_storage_object].
+#[derive(Clone, Copy)]
+pub(crate) struct ItemStore<'storage> {
+    storage_kind: &'storage StorageKind,
+    storage_object: Option<&'storage web_sys::Storage>,
+}
+
+impl<'storage> ItemStore<'storage> {
+    pub(crate) const fn new(
+        storage_kind: &'storage StorageKind,
+        storage_object: Option<&'storage web_sys::Storage>,
+    ) -> Self {
+        Self {
+            storage_kind,
+            storage_object,
+        }
+    }
+
+    /// Returns the Storage object given to the glue, or the one of its storage kind.
+    pub(crate) fn web_sys_storage(&self) -> Result<web_sys::Storage, Box<dyn Error>> {
+        match self.storage_object {
+            Some(storage) => Ok(storage.clone()),
+            None => self.storage_kind.web_sys_storage(),
+        }
+    }
+
+    pub(crate) fn get_item(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let Some(storage) = self.storage_object else {
+            return self.storage_kind.get_item(key);
+        };
+        storage
+            .get_item(key)
+            .map_log_possible_error(|_| format!("Could not get serialized value for key {key}"))
+    }
+
+    pub(crate) fn set_item<SerializedValue>(
+        &self,
+        key: &str,
+        value: SerializedValue,
+    ) -> Result<(), Box<dyn Error>>
+    where
+        SerializedValue: FnOnce() -> Result<String, Box<dyn Error>>,
+    {
+        let Some(storage) = self.storage_object else {
+            return self.storage_kind.set_item(key, value);
+        };
+        storage
+            .set_item(key, &value()?)
+            .map_log_possible_error(|err| {
+                format!("Could set serialized value for key {key} due to {err:?}")
+            })
+    }
+
+    /// Sets the serialized value just like [StorageKind::try_set_item], but writing it right away
+    /// on a Storage object, as the ``write_queue`` feature only queues writes by storage kind.
+    pub(crate) fn try_set_item(&self, key: &str, serialized: String) -> Result<(), StorageError> {
+        let Some(storage) = self.storage_object else {
+            return self.storage_kind.try_set_item(key, serialized);
+        };
+        storage
+            .set_item(key, &serialized)
+            .log_possible_error(|err| {
+                format!("Could set serialized value for key {key} due to {err:?}")
+            })
+            .map_err(StorageError::from_js)
+    }
+
+    pub(crate) fn remove_item(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        let Some(storage) = self.storage_object else {
+            return self.storage_kind.remove_item(key);
+        };
+        storage
+            .remove_item(key)
+            .map_log_possible_error(|err| format!("Could remove value of key {key} due to {err:?}"))
+    }
+
+    pub(crate) fn len(&self) -> Result<usize, Box<dyn Error>> {
+        let Some(storage) = self.storage_object else {
+            return self.storage_kind.len();
+        };
+        storage
+            .length()
+            .map(|len| len as usize)
+            .map_log_possible_error(|err| format!("Could not get amount of keys due to {err:?}"))
+    }
+
+    /// Owned copy of this, as kept by writes deferred until later.
+    pub(crate) fn into_owned(self) -> (StorageKind, Option<web_sys::Storage>) {
+        (*self.storage_kind, self.storage_object.cloned())
+    }
+}
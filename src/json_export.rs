@@ -0,0 +1,67 @@
+use crate::log_error::LogError;
+use crate::StorageError;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::error::Error;
+
+/// JSON document mapping the web names of the glues of a storage struct to their values.
+pub type JsonMap = serde_json::Map<String, serde_json::Value>;
+
+pub fn insert_json<Value: serde::Serialize>(
+    json: &mut JsonMap,
+    web_name: &str,
+    value: &Value,
+) -> Result<(), Box<dyn Error>> {
+    let value = serde_json::to_value(value)
+        .map_log_possible_error(|err| format!("Cannot export {web_name} as json due to {err:?}"))?;
+    json.insert(String::from(web_name), value);
+    Ok(())
+}
+
+pub fn json_to_string(json: &JsonMap) -> Result<String, Box<dyn Error>> {
+    serde_json::to_string(json)
+        .map_log_possible_error(|err| format!("Cannot serialize as json due to {err:?}"))
+}
+
+pub fn json_from_str(json: &str) -> Result<JsonMap, Box<dyn Error>> {
+    serde_json::from_str(json)
+        .map_log_possible_error(|err| format!("Cannot deserialize as json due to {err:?}"))
+}
+
+/// Removes the value of the web name from the document and deserializes it, if present.
+pub fn take_json<Value: for<'de> serde::de::Deserialize<'de>>(
+    json: &mut JsonMap,
+    web_name: &str,
+) -> Option<Result<Value, Box<dyn Error>>> {
+    json.remove(web_name).map(|value| {
+        serde_json::from_value(value).map_log_possible_error(|err| {
+            format!("Cannot import {web_name} from json due to {err:?}")
+        })
+    })
+}
+
+/// Fails if the document contains web names other than the indicated ones, listing them.
+pub fn check_json_keys(json: &JsonMap, web_names: &[&str]) -> Result<(), Box<dyn Error>> {
+    let unknown_web_names = json
+        .keys()
+        .filter(|web_name| !web_names.contains(&web_name.as_str()))
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    if unknown_web_names.is_empty() {
+        return Ok(());
+    }
+    None::<()>.map_log_possible_error(|_| {
+        format!("Unknown web names on json: {}", unknown_web_names.join(", "))
+    })
+}
+
+/// Turns a failure importing a JSON document into a [StorageError], being those that aren't
+/// already one due to the document or its values not being deserializable.
+pub fn import_error(error: Box<dyn Error>) -> StorageError {
+    match error.downcast::<StorageError>() {
+        Ok(error) => *error,
+        Err(error) => StorageError::SerdeDeserialize(error),
+    }
+}
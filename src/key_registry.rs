@@ -0,0 +1,43 @@
+use crate::log_error::log_error;
+use crate::StorageKind;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use critical_section::Mutex;
+
+/// Keys of every live glue on debug builds, along with the storage they target to, where a key
+/// appears once per glue using it.
+static LIVE_KEYS: Mutex<RefCell<Vec<(StorageKind, String)>>> = Mutex::new(RefCell::new(Vec::new()));
+
+/// Registers the key as used by a live glue, warning if another live glue already uses it, as
+/// both glues would overwrite each other when saved or dropped.
+pub(crate) fn register(storage_kind: StorageKind, key: &str) {
+    let key_is_shared = critical_section::with(|critical_section| {
+        let mut live_keys = LIVE_KEYS.borrow_ref_mut(critical_section);
+        let key_is_shared = live_keys
+            .iter()
+            .any(|(live_kind, live_key)| *live_kind == storage_kind && live_key == key);
+        live_keys.push((storage_kind, String::from(key)));
+        key_is_shared
+    });
+    if key_is_shared {
+        log_error(&format!(
+            "Two live glues use the key {key} of the {storage_kind:?} Storage, so they will \
+            overwrite each other when saved or dropped"
+        ));
+    }
+}
+
+/// Unregisters the key as used by a glue that is no longer live.
+pub(crate) fn unregister(storage_kind: StorageKind, key: &str) {
+    critical_section::with(|critical_section| {
+        let mut live_keys = LIVE_KEYS.borrow_ref_mut(critical_section);
+        let position = live_keys
+            .iter()
+            .position(|(live_kind, live_key)| *live_kind == storage_kind && live_key == key);
+        if let Some(position) = position {
+            live_keys.swap_remove(position);
+        }
+    });
+}
@@ -11,7 +11,7 @@
 //! For example, if your web app has an item 'MyPreferredColor' whose value is a String, you can
 //! associate it to a [StorageData] like this:
 //!
-//! ```rust
+//! ```rust no_run
 //! use storage_data::StorageData;
 //! const DEFAULT_COLOR : &str = "BLUE";
 //!
@@ -41,12 +41,12 @@
 //!
 //! - ``visited_times: usize``: Containing the times the user, visits a page.
 //! - ``picked_products: Vec<String>``: A list of products the user picked for buying, which is a
-//!    value that should be stored in a Session Storage rather than Local.
+//!   value that should be stored in a Session Storage rather than Local.
 //! - ``user_info: UserInfo``: A custom-made struct with personal information about the user.
 //!
 //! You could define a storage such as this:
 //!
-//! ```rust
+//! ```rust no_run
 //! use derive_web_storage::WebStorage;
 //!
 //! #[derive(Debug)]
@@ -78,6 +78,7 @@
 //!     user_info: UserInfo,
 //! }
 //!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
 //! struct UserInfo{
 //!     name:String, preferred_color: String,
 //! }
@@ -96,22 +97,99 @@
 
 #![no_std]
 extern crate alloc;
+#[cfg(any(feature = "write_queue", feature = "panic_flush", not(target_arch = "wasm32")))]
+extern crate std;
 #[cfg(feature = "derive")]
 pub extern crate derive_web_storage;
+pub extern crate serde;
+use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::Cell;
 use core::convert::AsRef;
 use core::error::Error;
 use core::fmt::{Debug, Display, Formatter};
+use core::marker::PhantomData;
 use core::ops::{Deref, DerefMut};
+use debounced_save::DebouncedSave;
+use item_store::ItemStore;
 use log_error::LogError;
+use serdes::Codec;
 use once_cell::sync::OnceCell;
+#[cfg(target_arch = "wasm32")]
+use wasm_bindgen::{JsCast, JsValue};
 use web_sys::wasm_bindgen::__rt::core;
+#[cfg(feature = "use_serde_json")]
+pub use serde_json::value::RawValue;
+#[cfg(target_arch = "wasm32")]
+pub(crate) mod cookie;
+pub(crate) mod debounced_save;
+#[cfg(all(feature = "indexeddb", target_arch = "wasm32"))]
+pub(crate) mod indexed_db;
+pub(crate) mod item_store;
+#[cfg(debug_assertions)]
+pub(crate) mod key_registry;
+pub(crate) mod listener;
 pub(crate) mod log_error;
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) mod memory_storage;
+#[cfg(feature = "panic_flush")]
+pub(crate) mod panic_flush;
+#[cfg(feature = "save_retry")]
+pub(crate) mod save_retry;
 pub(crate) mod serdes;
+pub(crate) mod storage_array;
+pub(crate) mod storage_backend;
+pub(crate) mod storage_entry;
+pub(crate) mod storage_error;
+#[cfg(feature = "use_serde_json")]
+pub(crate) mod storage_group;
+pub(crate) mod storage_log;
+pub(crate) mod storage_namespace;
+pub(crate) mod unload_flush;
+#[cfg(feature = "watch")]
+pub(crate) mod watch;
+#[cfg(feature = "write_queue")]
+pub(crate) mod write_queue;
+pub use listener::{ExternalChange, StorageListener};
+pub use log_error::{log_level, set_log_level, set_log_sink, LogLevel};
+#[cfg(feature = "panic_flush")]
+pub use panic_flush::install_panic_flush;
+pub use storage_array::StorageArray;
+pub use storage_backend::{Dynamic, Local, Session, StorageBackend};
+pub use storage_entry::StorageEntry;
+pub use storage_error::StorageError;
+#[cfg(feature = "use_serde_json")]
+pub use storage_group::StorageGroup;
+pub use storage_log::StorageLog;
+pub use storage_namespace::StorageNamespace;
+pub use unload_flush::install_beforeunload_flush;
+#[cfg(feature = "watch")]
+pub use watch::StorageWatch;
+#[cfg(feature = "write_queue")]
+pub use write_queue::flush_pending;
+#[cfg(feature = "indexeddb")]
+pub use serdes::SerializedPayload;
 
 pub(crate) mod macros;
+#[cfg(feature = "use_serde_json")]
+pub(crate) mod json_export;
+
+/// Items used by the code the macros generate, these aren't meant to be used directly.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::borrow::Cow;
+    #[cfg(not(target_arch = "wasm32"))]
+    pub use crate::memory_storage::reads as memory_storage_reads;
+    #[cfg(feature = "use_serde_json")]
+    pub use crate::json_export::{
+        check_json_keys, import_error, insert_json, json_from_str, json_to_string, take_json,
+        JsonMap,
+    };
+}
 
 //todo!(Allow to panic when data couldn't deserialize due to corruption)
 
@@ -121,21 +199,100 @@ pub(crate) mod macros;
 /// Web Storage is made of two kinds of storages:
 ///
 /// - Local: Persistent data, use this if you want data to be kept even if the user closes its
-/// web browser.
+///   web browser.
 /// - Session: Only kept as long as your web page is open in the user's web browser, if he closes
-/// either the tab or the web browser, it will be removed.
+///   either the tab or the web browser, it will be removed.
+///
+/// Besides these, cookies can be used as a fallback for environments where Web Storage is
+/// blocked, such as some restrictive webviews, see [StorageKind::Cookie].
 ///
 /// For more information visit: <https://developer.mozilla.org/en-US/docs/Web/API/Web_Storage_API>.
+///
+/// Off the web browser, that is, on targets other than ``wasm32``, such as on native tests, every
+/// storage kind keeps its items in memory for as long as the thread lives, so code using glues
+/// can be tested through ``cargo test`` without ``wasm-pack``.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum StorageKind {
     /// In this Storage the data is persistent.
     Local,
     /// In this Storage the data is kept as long as the web page is open in the web browser.
     Session,
+    /// Data is kept in the document's cookies, each one expiring after the indicated seconds.
+    ///
+    /// Keys and values are URL-encoded, and as web browsers only accept cookies of around 4KB,
+    /// setting a value whose cookie would exceed 4096 bytes fails. As this isn't a Web Storage,
+    /// [StorageKind::web_sys_storage] fails, and so do the listeners to changes from other tabs.
+    Cookie {
+        /// Seconds after which the cookie expires.
+        max_age_secs: u32,
+    },
+    /// Data is kept in the indicated object store of IndexedDB, which allows storing values far
+    /// exceeding the quota of Local Storage, such as big offline datasets.
+    ///
+    /// As IndexedDB is asynchronous, values are only reachable through the async functions of
+    /// [StorageData], such as [StorageData::get_async], [StorageData::set_async] and
+    /// [StorageData::save_async], while the sync functions of both [StorageKind] and
+    /// [StorageData] fail, meaning neither [Deref] can load the value, only giving back the one
+    /// loaded through [StorageData::get_async], or the default value if none was loaded, nor the
+    /// value is saved when the [StorageData] is dropped.
+    ///
+    /// Values of binary serde formats, such as bincode's and CBOR's, are kept as raw bytes rather
+    /// than encoded as base64, unless a layer such as compression, versioning or a time to live
+    /// is set over them, see [SerializedPayload].
+    #[cfg(feature = "indexeddb")]
+    IndexedDb {
+        /// Name of the object store, which is kept in a database of the same name.
+        store: &'static str,
+    },
+    /// Local Storage reached through the global scope of a worker rather than through the
+    /// ``window``, which is absent on workers.
+    ///
+    /// Note: Most web browsers don't expose Local Storage to workers, in which case this storage
+    /// kind fails just like when the Storage isn't reachable.
+    Worker,
+}
+
+/// What a [StorageData] does when there is no ``window`` to reach the Storage through, such as
+/// during server-side rendering or in a worker, telling apart a key being absent from the
+/// Storage not being reachable at all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NoWindowPolicy {
+    /// Values fall back to their default value, just like when the key is absent.
+    Default,
+    /// Accessing the value panics.
+    Panic,
+    /// [StorageData::try_get] fails, while the accessors that can't fail, such as
+    /// [StorageData::get], fall back to the default value.
+    Error,
 }
+
 impl StorageKind {
+    /// Tells whether this storage kind needs a ``window`` to be reached, while there is none.
+    pub(crate) fn window_is_missing(&self) -> bool {
+        cfg!(target_arch = "wasm32") && !matches!(self, StorageKind::Worker) && window().is_none()
+    }
+
     /// Returns the [web_sys::Storage] corresponding to this storage kind.
+    ///
+    /// This fails off the web browser, where items are kept in memory, see [StorageKind].
     pub fn web_sys_storage(&self) -> Result<web_sys::Storage, Box<dyn Error>> {
-        let window = web_sys::window().map_log_possible_error(|_| "Could not get windows")?;
+        #[cfg(not(target_arch = "wasm32"))]
+        return None.map_log_possible_error(|_| "There is no Web Storage off the web browser");
+        #[cfg(target_arch = "wasm32")]
+        self.browser_storage()
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn browser_storage(&self) -> Result<web_sys::Storage, Box<dyn Error>> {
+        if let StorageKind::Worker = self {
+            let scope = web_sys::js_sys::global()
+                .dyn_into::<web_sys::WorkerGlobalScope>()
+                .map_log_possible_error(|_| "Could not get worker's global scope")?;
+            return web_sys::js_sys::Reflect::get(&scope, &JsValue::from_str("localStorage"))
+                .ok()
+                .and_then(|storage| storage.dyn_into::<web_sys::Storage>().ok())
+                .map_log_possible_error(|_| "Could not get Local Storage from worker");
+        }
+        let window = window().map_log_possible_error(|_| "Could not get windows")?;
         match self {
             StorageKind::Local => window
                 .local_storage()
@@ -145,10 +302,33 @@ impl StorageKind {
                 .session_storage()
                 .map_log_possible_error(|err| format!("Could not get Session Storage ({err:?})"))?
                 .map_log_possible_error(|_| "Could not get Session Storage"),
+            StorageKind::Cookie { .. } => {
+                None.map_log_possible_error(|_| "Cookies are not a Web Storage")
+            }
+            #[cfg(feature = "indexeddb")]
+            StorageKind::IndexedDb { .. } => None.map_log_possible_error(|_| {
+                "IndexedDB is not a Web Storage, it can only be used through async functions"
+            }),
+            StorageKind::Worker => unreachable!(),
         }
     }
     /// Gets an item using this item's key.
     pub fn get_item(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        #[cfg(feature = "write_queue")]
+        if let Some(serialized) = write_queue::pending(self, key) {
+            return Ok(Some(serialized));
+        }
+        self.read_item(key)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_item(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        Ok(memory_storage::get_item(self, key))
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn read_item(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        if let StorageKind::Cookie { .. } = self {
+            return cookie::get_item(key);
+        }
         self.web_sys_storage()?
             .get_item(key)
             .map_log_possible_error(|_| format!("Could not get serialized value for key {key}"))
@@ -162,89 +342,383 @@ impl StorageKind {
     where
         SerializedValue: FnOnce() -> Result<String, Box<dyn Error>>,
     {
+        #[cfg(feature = "write_queue")]
+        write_queue::discard(self, key);
+        self.overwrite_item(key, value()?)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn overwrite_item(&self, key: &str, serialized: String) -> Result<(), Box<dyn Error>> {
+        memory_storage::set_item(self, key, serialized);
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn overwrite_item(&self, key: &str, serialized: String) -> Result<(), Box<dyn Error>> {
+        if let StorageKind::Cookie { max_age_secs } = self {
+            return cookie::set_item(key, &serialized, *max_age_secs);
+        }
         self.web_sys_storage()?
-            .set_item(key, &value()?)
+            .set_item(key, &serialized)
             .map_log_possible_error(|err| {
                 format!("Could set serialized value for key {key} due to {err:?}")
             })?;
         Ok(())
     }
+    /// Sets the value of an item using this item's key, returning the value it replaced, if any,
+    /// which is useful for auditing or undoing changes.
+    pub fn replace_item(&self, key: &str, value: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let previous = self.get_item(key)?;
+        self.set_item(key, || Ok(String::from(value)))?;
+        Ok(previous)
+    }
+    /// Sets the serialized value of an item using this item's key, telling apart the reason of the
+    /// failure through a [StorageError], such as the Storage being full.
+    ///
+    /// When the ``write_queue`` feature is enabled, the value is enqueued rather than written,
+    /// never failing, see [flush_pending].
+    pub fn try_set_item(&self, key: &str, serialized: String) -> Result<(), StorageError> {
+        #[cfg(feature = "write_queue")]
+        {
+            write_queue::enqueue(*self, key, serialized);
+            Ok(())
+        }
+        #[cfg(not(feature = "write_queue"))]
+        self.write_item(key, serialized)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn write_item(&self, key: &str, serialized: String) -> Result<(), StorageError> {
+        memory_storage::set_item(self, key, serialized);
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn write_item(&self, key: &str, serialized: String) -> Result<(), StorageError> {
+        if let StorageKind::Cookie { max_age_secs } = self {
+            return cookie::set_item(key, &serialized, *max_age_secs)
+                .map_err(StorageError::StorageUnavailable);
+        }
+        self.web_sys_storage()
+            .map_err(StorageError::StorageUnavailable)?
+            .set_item(key, &serialized)
+            .log_possible_error(|err| {
+                format!("Could set serialized value for key {key} due to {err:?}")
+            })
+            .map_err(StorageError::from_js)
+    }
     /// Removes the key and value of an item.
     pub fn remove_item(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        #[cfg(feature = "write_queue")]
+        write_queue::discard(self, key);
+        self.delete_item(key)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn delete_item(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        memory_storage::remove_item(self, key);
+        Ok(())
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn delete_item(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        if let StorageKind::Cookie { .. } = self {
+            return cookie::remove_item(key);
+        }
         self.web_sys_storage()?
             .remove_item(key)
             .map_log_possible_error(|err| format!("Could remove value of key {key} due to {err:?}"))
     }
+    /// Amount of keys in this storage, which is cheaper to retrieve than its size in bytes, as it
+    /// only takes a single call to the Storage.
+    pub fn len(&self) -> Result<usize, Box<dyn Error>> {
+        self.count_items()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn count_items(&self) -> Result<usize, Box<dyn Error>> {
+        Ok(memory_storage::len(self))
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn count_items(&self) -> Result<usize, Box<dyn Error>> {
+        if let StorageKind::Cookie { .. } = self {
+            return cookie::len();
+        }
+        self.web_sys_storage()?
+            .length()
+            .map(|len| len as usize)
+            .map_log_possible_error(|err| format!("Could not get amount of keys due to {err:?}"))
+    }
+    /// Tells whether this storage contains no keys.
+    pub fn is_empty(&self) -> Result<bool, Box<dyn Error>> {
+        Ok(self.len()? == 0)
+    }
+    /// Gets an item using this item's key, being this the only way of doing so for IndexedDB,
+    /// where values kept as raw bytes are given back encoded as base64.
+    #[cfg(feature = "indexeddb")]
+    pub async fn get_item_async(&self, key: &str) -> Result<Option<String>, Box<dyn Error>> {
+        let payload = self.get_payload_async(key).await?;
+        Ok(payload.map(SerializedPayload::into_text))
+    }
+    /// Sets the serialized value of an item using this item's key, being this the only way of
+    /// doing so for IndexedDB.
+    #[cfg(feature = "indexeddb")]
+    pub async fn set_item_async(&self, key: &str, serialized: String) -> Result<(), Box<dyn Error>> {
+        self.set_payload_async(key, SerializedPayload::Text(serialized)).await
+    }
+    /// Gets an item using this item's key just like [StorageKind::get_item_async], but giving
+    /// back values kept as raw bytes as they are, rather than encoded as base64.
+    #[cfg(feature = "indexeddb")]
+    pub async fn get_payload_async(
+        &self,
+        key: &str,
+    ) -> Result<Option<SerializedPayload>, Box<dyn Error>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return Ok(self.get_item(key)?.map(SerializedPayload::Text));
+        #[cfg(target_arch = "wasm32")]
+        match self {
+            StorageKind::IndexedDb { store } => indexed_db::get_payload(store, key).await,
+            _ => Ok(self.get_item(key)?.map(SerializedPayload::Text)),
+        }
+    }
+    /// Sets the serialized value of an item using this item's key just like
+    /// [StorageKind::set_item_async], keeping raw bytes as they are on IndexedDB, while the other
+    /// storage kinds, only able to keep text, keep them encoded as base64.
+    #[cfg(feature = "indexeddb")]
+    pub async fn set_payload_async(
+        &self,
+        key: &str,
+        payload: SerializedPayload,
+    ) -> Result<(), Box<dyn Error>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.set_item(key, || Ok(payload.into_text()));
+        #[cfg(target_arch = "wasm32")]
+        match self {
+            StorageKind::IndexedDb { store } => indexed_db::set_payload(store, key, payload).await,
+            _ => self.set_item(key, || Ok(payload.into_text())),
+        }
+    }
+    /// Removes the key and value of an item, being this the only way of doing so for IndexedDB.
+    #[cfg(feature = "indexeddb")]
+    pub async fn remove_item_async(&self, key: &str) -> Result<(), Box<dyn Error>> {
+        #[cfg(not(target_arch = "wasm32"))]
+        return self.remove_item(key);
+        #[cfg(target_arch = "wasm32")]
+        match self {
+            StorageKind::IndexedDb { store } => indexed_db::remove_item(store, key).await,
+            _ => self.remove_item(key),
+        }
+    }
+}
+/// Tells whether the indicated storage can be written to, as on some web browsers, such as on
+/// private browsing, the storage might be absent, or it might be present but throw on every write.
+///
+/// This is checked by writing and then removing a throwaway key, which is always removed, even if
+/// writing it failed, and which is namespaced as ``storage_data::availability_probe`` to avoid
+/// clashing with the user's keys.
+///
+/// When the storage isn't available, apps might fall back to keeping their state in memory.
+pub fn is_available(storage_kind: &StorageKind) -> bool {
+    const PROBE_KEY: &str = "storage_data::availability_probe";
+    let written = storage_kind
+        .set_item(PROBE_KEY, || Ok(String::from(PROBE_KEY)))
+        .is_ok();
+    let removed = storage_kind.remove_item(PROBE_KEY).is_ok();
+    written && removed
+}
+/// Outcome of reading a key through [get_data_detailed], telling apart a key that isn't present
+/// from one whose value could not be deserialized, and from a Storage that could not be read.
+#[derive(Debug)]
+pub enum Loaded<Value> {
+    /// The key isn't present on the Storage.
+    Absent,
+    /// The Storage could not be read, such as when the web browser blocks it on private browsing,
+    /// keeping the reason why.
+    Unavailable(Box<dyn Error>),
+    /// The key is present and its value was deserialized.
+    Parsed(Value),
+    /// The key is present but its value could not be deserialized, keeping the raw serialized
+    /// value, so it can be migrated, inspected, or backed up before being overwritten.
+    Corrupt {
+        /// Serialized value as found on the Storage.
+        raw: String,
+        /// Reason why the value could not be deserialized.
+        error: Box<dyn Error>,
+    },
+}
+
+/// Gets the value contained in the specified key for this storage kind, telling apart whether
+/// the Storage could not be read, whether the key is absent, whether its value was deserialized,
+/// or whether its value is present but could not be deserialized, on which case the raw
+/// serialized value is returned along with the deserialization error.
+pub fn get_data_detailed<Key, Value, Deserialize>(
+    storage_kind: &StorageKind,
+    key: Key,
+    deserialize: Deserialize,
+) -> Loaded<Value>
+where
+    Key: AsRef<str>,
+    Deserialize: FnOnce(String) -> Result<Value, Box<dyn Error>>,
+{
+    let key = key.as_ref();
+    parse_loaded(storage_kind.get_item(key), key, deserialize)
+}
+/// Tells apart the outcomes of loading the value of a key from the result of reading it, see
+/// [get_data_detailed].
+fn parse_loaded<Value, Deserialize>(
+    read: Result<Option<String>, Box<dyn Error>>,
+    key: &str,
+    deserialize: Deserialize,
+) -> Loaded<Value>
+where
+    Deserialize: FnOnce(String) -> Result<Value, Box<dyn Error>>,
+{
+    let raw = match read {
+        Ok(Some(raw)) => raw,
+        Ok(None) => return Loaded::Absent,
+        Err(error) => return Loaded::Unavailable(error),
+    };
+    match deserialize(raw.clone()).map_log_possible_error(|error| {
+        format!("Could not deserialize item for key {key} due to:\n{error}")
+    }) {
+        Ok(value) => Loaded::Parsed(value),
+        Err(error) => Loaded::Corrupt { raw, error },
+    }
 }
 /// Gets the value contained in the specified key for this storage kind, and if the
 /// storage doesn't contain said key, it returns the default value indicated by parameter.
 ///
 /// This operation can fail if the item could not be deserialized, as Storage only store [String]s
 /// on which we can represent this value as a serialized value, specifying this deserialization
-/// error as an ``Err<Box<dyn<Error>>>``.
+/// error as a [StorageError::SerdeDeserialize], use [get_data_detailed] to also get the raw
+/// value; it also fails if the Storage could not be read, specifying this error as a
+/// [StorageError::StorageUnavailable], rather than returning the default value as if the key was
+/// absent.
 pub fn get_data_with<Key, Value, DefaultValue, Deserialize>(
     storage_kind: &StorageKind,
     key: Key,
     default: DefaultValue,
     deserialize: Deserialize,
-) -> Result<Value, Box<dyn Error>>
+) -> Result<Value, StorageError>
 where
     Key: AsRef<str>,
     DefaultValue: FnOnce() -> Value,
     Deserialize: FnOnce(String) -> Result<Value, Box<dyn Error>>,
 {
-    let key = key.as_ref();
-    match storage_kind.get_item(key).ok().flatten().map(|as_string| {
-        deserialize(as_string).map_log_possible_error(|error| {
-            format!("Could not deserialize item for key {key} due to:\n{error}")
-        })
-    }) {
-        None => Ok(default()),
-        Some(Ok(value)) => Ok(value),
-        Some(Err(err)) => Err(err),
+    match get_data_detailed(storage_kind, key, deserialize) {
+        Loaded::Absent => Ok(default()),
+        Loaded::Unavailable(error) => Err(StorageError::StorageUnavailable(error)),
+        Loaded::Parsed(value) => Ok(value),
+        Loaded::Corrupt { error, .. } => Err(StorageError::SerdeDeserialize(error)),
     }
 }
 /// Sets the specified value as serialized string over the specified key for this storage kind.
 ///
-/// This operation can fail if the item could not be serialized, specifying this error
-/// as an ``Err<Box<dyn<Error>>>``.
+/// This operation can fail if the item could not be serialized or set, specifying this error
+/// as a [StorageError].
 pub fn set_data<Key, Value, Serialize>(
     storage_kind: &StorageKind,
     key: Key,
     value: &Value,
     serialize: Serialize,
-) -> Result<(), Box<dyn Error>>
+) -> Result<(), StorageError>
 where
     Key: AsRef<str>,
     Serialize: FnOnce(&Value) -> Result<String, Box<dyn Error>>,
 {
-    let key = key.as_ref();
-    storage_kind.set_item(key, || {
-        serialize(&value).log_possible_error(|error| {
+    set_data_on(ItemStore::new(storage_kind, None), key.as_ref(), value, serialize)
+}
+/// Sets the specified value as serialized string over the specified key just like [set_data],
+/// but on the storage a glue writes its items to.
+fn set_data_on<Value, Serialize>(
+    item_store: ItemStore<'_>,
+    key: &str,
+    value: &Value,
+    serialize: Serialize,
+) -> Result<(), StorageError>
+where
+    Serialize: FnOnce(&Value) -> Result<String, Box<dyn Error>>,
+{
+    let serialized = serialize(value)
+        .log_possible_error(|error| {
             format!("Could not serialize item for key {key} due to:\n{error:?}")
         })
-    })?;
-    Ok(())
+        .map_err(StorageError::SerdeSerialize)?;
+    item_store.try_set_item(key, serialized)
 }
 /// Glue over a Local/Session Storage key and its value.
 ///
 /// Used to retrieve and set the value without requiring to manually interacting with the Web
 /// Storage API.
-pub struct StorageData<Key, Value>
+///
+/// The storage targeted can also be fixed at the type level through the ``Backend``, see
+/// [StorageBackend].
+pub struct StorageData<Key, Value, Backend = Dynamic>
 where
     Key: AsRef<str>,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
 {
     storage_kind: StorageKind,
+    storage_object: Option<web_sys::Storage>,
+    backend: PhantomData<Backend>,
     key: Key,
     value: OnceCell<Value>,
     default_value: fn() -> Value,
     panic_on_cannot_deserialize: bool,
     save_on_drop: bool,
-    deserialize_as: fn(String) -> Result<Value, Box<dyn Error>>,
-    serialize_as: fn(&Value) -> Result<String, Box<dyn Error>>,
+    codec: Codec<Value>,
+    on_change: Option<OnChange<Value>>,
+    history_depth: usize,
+    on_conflict: Option<OnConflict<Value>>,
+    read_mtime: Cell<Option<u64>>,
+    debounced_save: Option<DebouncedSave>,
+    idle_save: Option<DebouncedSave>,
+    no_window_policy: NoWindowPolicy,
+    verify_roundtrip: Option<VerifyRoundtrip<Value>>,
+    fallback_key: Option<&'static str>,
+    migrate_fallback_key: bool,
+    prune_defaults: Option<IsDefault<Value>>,
+    #[cfg(debug_assertions)]
+    registered_key: Cell<bool>,
+    #[cfg(feature = "watch")]
+    watchers: Vec<(watch::SharedChannel<Value>, StorageListener)>,
     mutated: bool,
+    unbound: bool,
+}
+
+/// Function upgrading a serialized value stored with an older version, receiving said version and
+/// the serialized value, and returning the serialized value upgraded to the current version.
+pub type Migration = fn(u32, String) -> Result<String, Box<dyn Error>>;
+
+/// Function resolving a conflict between the value of a glue and a newer one written to the
+/// Storage by someone else, receiving both of them, and returning the value to save.
+pub type OnConflict<Value> = fn(local: &Value, stored: &Value) -> Value;
+
+/// Check of a value deserializing back into itself from its serialized form.
+type VerifyRoundtrip<Value> = fn(&Codec<Value>, &Value, &str) -> Result<(), Box<dyn Error>>;
+
+/// Check of a value being equal to the one given by the default value function.
+type IsDefault<Value> = fn(&Value, fn() -> Value) -> bool;
+
+/// Gets the ``window``, which is never present off the web browser, as reaching it there panics.
+pub(crate) fn window() -> Option<web_sys::Window> {
+    #[cfg(target_arch = "wasm32")]
+    return web_sys::window();
+    #[cfg(not(target_arch = "wasm32"))]
+    None
+}
+
+/// Milliseconds elapsed since the Unix epoch.
+pub(crate) fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    return web_sys::js_sys::Date::now();
+    #[cfg(not(target_arch = "wasm32"))]
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_millis() as f64)
 }
+
+/// Explanation given when there is no ``window`` to reach the Storage through.
+const NO_WINDOW: &str = "Could not reach the Storage, as there is no window, such as during \
+    server-side rendering or in a worker";
+
+/// Callback invoked with a value once it's persisted in the Storage.
+pub type OnChange<Value> = Rc<dyn Fn(&Value)>;
+
 #[cfg(feature = "default_storage_local")]
 /// Default storage used for new [StorageData]s, it is currently set to Local Storage.
 #[cfg(feature = "default_storage_local")]
@@ -265,55 +739,33 @@ where
     /// When trying to retrieve the value, this might not be set yet in the storage,
     /// in which case the default value is got back from the indicated closure.
     pub const fn new(key: Key, default: fn() -> Value) -> Self {
-        Self {
-            storage_kind: DEFAULT_STORAGE_KIND,
-
-            key,
-            value: OnceCell::new(),
-            default_value: default,
-            panic_on_cannot_deserialize: true,
-            save_on_drop: true,
-            mutated: false,
-
-            #[cfg(feature = "default_serde_json")]
-            serialize_as: serdes::serialize_json,
-            #[cfg(feature = "default_serde_json")]
-            deserialize_as: serdes::deserialize_json,
-
-            #[cfg(feature = "default_serde_bincode")]
-            serialize_as: serdes::serialize_bincode,
-            #[cfg(feature = "default_serde_bincode")]
-            deserialize_as: serdes::deserialize_bincode,
-
-            #[cfg(feature = "default_serde_yaml")]
-            serialize_as: serdes::serialize_yaml,
-            #[cfg(feature = "default_serde_yaml")]
-            deserialize_as: serdes::deserialize_yaml,
-
-            #[cfg(feature = "default_serde_ron")]
-            serialize_as: serdes::serialize_ron,
-            #[cfg(feature = "default_serde_ron")]
-            deserialize_as: serdes::deserialize_ron,
+        Self::with_backend(key, default)
+    }
 
-            #[cfg(feature = "default_serde_cbor")]
-            serialize_as: serdes::serialize_cbor,
-            #[cfg(feature = "default_serde_cbor")]
-            deserialize_as: serdes::deserialize_cbor,
+    /// Creates a glue to the key indicated just like [StorageData::new], but failing right away
+    /// if the Storage can't be reached, such as when the web browser disables it, rather than
+    /// on the first access, so apps can decide up front to keep their state in memory instead.
+    ///
+    /// Off the web browser, where items are kept in memory, this never fails.
+    pub fn try_new(key: Key, default: fn() -> Value) -> Result<Self, Box<dyn Error>> {
+        let storage_data = Self::new(key, default);
+        if cfg!(target_arch = "wasm32") {
+            storage_data.storage_kind.web_sys_storage()?;
         }
+        Ok(storage_data)
     }
-}
-impl<Key, Value> StorageData<Key, Value>
-where
-    Key: AsRef<str>,
-    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
-{
-    /// Specifies whether this value is automatically saved when the [StorageData] is drop.
+
+    /// Creates a glue to the key indicated already holding the value indicated, so the Storage
+    /// isn't read, this is useful when the value is already known, such as when hydrating state
+    /// rendered by a server.
     ///
-    /// Note: Updates only happen when the value has been updated or if the key isn't present in the
-    /// storage, meaning only the truly necessary saves are executed.
-    pub const fn save_on_drop(mut self, update_on_drop: bool) -> Self {
-        self.save_on_drop = update_on_drop;
-        self
+    /// The value is marked as mutated, so the first save or drop persists it, overwriting the
+    /// value in the Storage, if any.
+    pub fn from_value(key: Key, value: Value, default: fn() -> Value) -> Self {
+        let mut data = Self::new(key, default);
+        data.value = OnceCell::with_value(value);
+        data.mutated = true;
+        data
     }
 
     /// Specifies the kind of storage this glue targets to, being this either Local or Session.
@@ -332,107 +784,1178 @@ where
         self.with_storage(StorageKind::Session)
     }
 
-    /// Specifies how the value is serialized when setting on the Storage.
-    pub const fn serialize_with(
-        mut self,
-        serialize: fn(&Value) -> Result<String, Box<dyn Error>>,
-    ) -> Self {
-        self.serialize_as = serialize;
+    /// Specifies the Storage this glue reads and writes its value from, rather than the one of
+    /// its storage kind, such as the Local Storage of an iframe or a fake Storage on tests, while
+    /// the storage kind is still reported by [StorageData::storage_kind], and still used by the
+    /// async functions, as these are meant for [StorageKind::IndexedDb].
+    ///
+    /// The glue keeps a handle to the Storage for as long as it lives, keeping the JavaScript
+    /// object alive too, but not the document it belongs to, so when that of an iframe is
+    /// unloaded, such as when the iframe is removed or navigates away, the Storage might throw
+    /// or be detached, making reads and writes fail rather than reaching another Storage.
+    ///
+    /// ```rust no_run
+    /// use storage_data::{StorageData, StorageKind};
+    ///
+    /// // Any Storage object, such as the one of an iframe's window.
+    /// let storage = StorageKind::Session.web_sys_storage().unwrap();
+    /// let mut volume = StorageData::new("volume", || 100_u8).with_storage_object(storage);
+    /// volume.set(50).unwrap();
+    /// ```
+    pub fn with_storage_object(mut self, storage: web_sys::Storage) -> Self {
+        self.storage_object = Some(storage);
         self
     }
-
-    /// Specifies how the value is deserialized when retrieving it from the Storage.
-    pub const fn deserialize_with(
-        mut self,
-        serialize: fn(String) -> Result<Value, Box<dyn Error>>,
-    ) -> Self {
-        self.deserialize_as = serialize;
-        self
+}
+impl<Key, Value> StorageData<Key, Value, Local>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates a glue to the key indicated just like [StorageData::new], but always targeting
+    /// Local Storage, as told by its type, see [StorageBackend].
+    pub const fn local(key: Key, default: fn() -> Value) -> Self {
+        Self::with_backend(key, default)
     }
-
-    /// Specifies how the value is serialized when setting on the Storage and how it is deserialized
-    /// when retrieving it from the Storage.
-    pub const fn serde_with(
-        self,
-        serialize: fn(&Value) -> Result<String, Box<dyn Error>>,
-        deserialize: fn(String) -> Result<Value, Box<dyn Error>>,
-    ) -> Self {
-        self.serialize_with(serialize).deserialize_with(deserialize)
+}
+impl<Key, Value> StorageData<Key, Value, Session>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates a glue to the key indicated just like [StorageData::new], but always targeting
+    /// Session Storage, as told by its type, see [StorageBackend].
+    pub const fn session(key: Key, default: fn() -> Value) -> Self {
+        Self::with_backend(key, default)
     }
+}
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    const fn with_backend(key: Key, default: fn() -> Value) -> Self {
+        Self {
+            storage_kind: Backend::STORAGE_KIND,
+            storage_object: None,
+            backend: PhantomData,
 
-    /// Sets serialization and deserialization as JSON's.
-    #[cfg(feature = "use_serde_json")]
-    pub const fn serde_json(self) -> Self {
-        self.serde_with(serdes::serialize_json, serdes::deserialize_json)
+            key,
+            value: OnceCell::new(),
+            default_value: default,
+            panic_on_cannot_deserialize: true,
+            save_on_drop: true,
+            mutated: false,
+            unbound: false,
+            codec: Codec::new(),
+            on_change: None,
+            history_depth: 0,
+            on_conflict: None,
+            read_mtime: Cell::new(None),
+            debounced_save: None,
+            idle_save: None,
+            no_window_policy: NoWindowPolicy::Default,
+            verify_roundtrip: None,
+            fallback_key: None,
+            migrate_fallback_key: false,
+            prune_defaults: None,
+            #[cfg(debug_assertions)]
+            registered_key: Cell::new(false),
+            #[cfg(feature = "watch")]
+            watchers: Vec::new(),
+        }
     }
-
-    /// Sets serialization and deserialization as bincode's.
-    #[cfg(feature = "use_serde_bincode")]
-    pub const fn serde_bincode(self) -> Self {
-        self.serde_with(serdes::serialize_bincode, serdes::deserialize_bincode)
+}
+impl<Key, Value> StorageData<Key, Value>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + Default,
+{
+    /// Creates a glue to the key indicated, whose default value, used when the value isn't set in
+    /// the storage yet, is the one given by [Default].
+    pub const fn new_default(key: Key) -> Self {
+        Self::new(key, Value::default)
+    }
+}
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + PartialEq,
+{
+    /// Specifies whether values are deserialized back right after serializing them on
+    /// [StorageData::set] and [StorageData::save], failing if they differ from the original value,
+    /// this catches lossy combinations of types and serde formats when writing rather than on a
+    /// later read, such as floats losing precision, or maps with non-string keys on JSON.
+    ///
+    /// This is disabled by default, as it doubles the work of every write.
+    pub const fn verify_roundtrip(mut self, enabled: bool) -> Self {
+        self.verify_roundtrip = match enabled {
+            true => Some(Codec::verify_roundtrip),
+            false => None,
+        };
+        self
     }
 
-    /// Sets serialization and deserialization as YAML's.
-    #[cfg(feature = "use_serde_yaml")]
-    pub const fn serde_yaml(self) -> Self {
-        self.serde_with(serdes::serialize_yaml, serdes::deserialize_yaml)
+    /// Specifies whether saving a value equal to the default value removes the key from the
+    /// Storage rather than writing it, keeping the Storage tidy and saving space, which doesn't
+    /// change what is read, as an absent key is read as the default value.
+    ///
+    /// The value is compared against a freshly computed default value, and only values that were
+    /// mutated are saved, so keys whose stored value is corrupt aren't removed just because this
+    /// glue fell back to its default value when reading them.
+    pub const fn prune_defaults(mut self, enabled: bool) -> Self {
+        self.prune_defaults = match enabled {
+            true => Some(|value, default| *value == default()),
+            false => None,
+        };
+        self
     }
+}
 
-    /// Sets serialization and deserialization as RON's.
-    #[cfg(feature = "use_serde_ron")]
-    pub const fn serde_ron(self) -> Self {
-        self.serde_with(serdes::serialize_ron, serdes::deserialize_ron)
+impl<Value> StorageData<String, Value>
+where
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates a glue to the key indicated, where the key is built at runtime, such as when
+    /// formatting an id into it.
+    ///
+    /// Use [StorageData::new] for keys known at compile time, as it's const, allowing to create
+    /// glues on statics and constants, while this is meant for keys computed at runtime, for
+    /// which [crate::StorageNamespace] might also be useful.
+    pub fn new_with_string(key: String, default: fn() -> Value) -> Self {
+        Self::new(key, default)
     }
 
-    /// Sets serialization and deserialization as cbor's.
-    #[cfg(feature = "use_serde_cbor")]
-    pub const fn serde_cbor(self) -> Self {
-        self.serde_with(serdes::serialize_cbor, serdes::deserialize_cbor)
+    /// Creates a glue whose key is derived from the name of the value's type, this is useful for
+    /// singletons, as it avoids having to write down a key for them.
+    ///
+    /// The key is taken from [core::any::type_name], replacing every character other than
+    /// alphanumerics, ``_`` and ``:`` with ``_``, so ``Vec<my_crate::UserInfo>`` is stored under
+    /// ``alloc::vec::Vec_my_crate::UserInfo_``.
+    ///
+    /// Note: [core::any::type_name] isn't guaranteed to be stable across compiler versions, so
+    /// updating the compiler might change the key, losing access to values stored previously, if
+    /// this is a problem, use [StorageData::new] with an explicit key instead.
+    pub fn for_type(default: fn() -> Value) -> Self {
+        let key = core::any::type_name::<Value>()
+            .chars()
+            .map(|char| match char {
+                'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | ':' => char,
+                _ => '_',
+            })
+            .collect::<String>();
+        Self::new(key, default)
     }
+}
 
-    /// Gets the current value, if is not set, it retrieves it from the Storage through a
-    /// deserialization, and if not present, it gets it as the default value.
-    fn resolve(&self) -> &Value {
-        self.value.get_or_init(|| {
-            let value = get_data_with(
-                &self.storage_kind,
-                self.key.as_ref(),
-                self.default_value,
-                self.deserialize_as,
-            );
-            match (value, self.panic_on_cannot_deserialize) {
-                (Ok(value), _) => value,
-                (Err(_), false) => (self.default_value)(),
-                (Err(error), true) => panic!("{error}"),
-            }
-        })
+#[cfg(feature = "use_serde_json")]
+impl<Key> StorageData<Key, Box<RawValue>>
+where
+    Key: AsRef<str>,
+{
+    /// Creates a glue to the key indicated whose value is opaque JSON, this JSON is stored and
+    /// retrieved verbatim, without reordering its fields or losing precision on its numbers, as it
+    /// is never interpreted as a concrete type.
+    ///
+    /// This glue always uses JSON for serialization and deserialization, regardless of the
+    /// default serde format set through the features, as the raw value can only be written
+    /// verbatim by JSON.
+    pub const fn new_raw_json(key: Key, default: fn() -> Box<RawValue>) -> Self {
+        Self::new(key, default).serde_json()
     }
+}
 
-    /// Gets the current value from the glue.
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize
+        + for<'de> serde::de::Deserialize<'de>
+        + Clone
+        + Into<u32>
+        + TryFrom<u32>,
+{
+    /// Sets serialization and deserialization as the integer discriminant of the value, which is
+    /// much more compact than the name of the variant serde formats usually store, such as JSON's
+    /// ``"VariantName"``, being useful for enums stored frequently.
     ///
-    /// This might not be up to date with the Storage if the Storage is modified outside this
-    /// [StorageData].
-    pub fn get(&self) -> &Value {
-        self.resolve()
+    /// This is only meant for C-like enums, where every variant must be representable as an
+    /// integer through [Into<u32>], and where [TryFrom<u32>] must give back the same variant,
+    /// failing for integers that don't belong to any variant, for example:
+    ///
+    /// ```rust
+    /// #[derive(Clone, Copy, serde::Serialize, serde::Deserialize)]
+    /// enum State { Idle = 0, Running = 1 }
+    ///
+    /// impl From<State> for u32 {
+    ///     fn from(state: State) -> u32 { state as u32 }
+    /// }
+    ///
+    /// impl TryFrom<u32> for State {
+    ///     type Error = ();
+    ///     fn try_from(discriminant: u32) -> Result<Self, ()> {
+    ///         match discriminant {
+    ///             0 => Ok(State::Idle),
+    ///             1 => Ok(State::Running),
+    ///             _ => Err(()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let state = storage_data::StorageData::new("state", || State::Idle).with_enum_as_int();
+    /// # core::mem::forget(state);
+    /// ```
+    pub const fn with_enum_as_int(self) -> Self {
+        self.serde_with(serdes::serialize_enum_as_int, serdes::deserialize_enum_as_int)
     }
+}
 
-    /// Gets the current value from the glue.
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + AsRef<str> + From<String>,
+{
+    /// Stores the string verbatim, reading it back as is, rather than serializing it through a
+    /// serde format, which would surround it with quotes and escape it, as JSON does, wasting
+    /// space of the Storage, this also allows reading strings written by plain JavaScript:
     ///
-    /// This might not be up to date with the Storage if the Storage is modified outside this
-    /// [StorageData].
-    pub fn get_mut(&mut self) -> &mut Value {
-        self.resolve();
-        self.value.get_mut().unwrap()
+    /// ```rust no_run
+    /// use storage_data::{StorageData, StorageKind};
+    ///
+    /// let mut name = StorageData::new("name", String::new).raw_string();
+    /// name.set(String::from("Jorge")).unwrap();
+    ///
+    /// // The value lands in the Storage without any JSON quoting.
+    /// assert_eq!(
+    ///     StorageKind::Local.get_item("name").unwrap(),
+    ///     Some(String::from("Jorge"))
+    /// );
+    /// ```
+    ///
+    /// The string is still compressed, versioned or given an expiry if requested through
+    /// ``compressed``, [StorageData::versioned] or [StorageData::with_ttl].
+    pub const fn raw_string(self) -> Self {
+        self.serde_with(serdes::serialize_raw_string, serdes::deserialize_raw_string)
     }
 
-    /// Sets the value for both this glue and the Storage, meaning the Storage should be up to date
-    /// after calling this function.
+    /// Reads stored strings that fail to deserialize through the serde format as the string
+    /// itself, such as bare strings written by hand-rolled JavaScript through
+    /// ``localStorage.setItem``, which aren't quoted as JSON expects, while values are still
+    /// written through the serde format, easing the adoption of this crate on existing codebases:
+    ///
+    /// ```rust no_run
+    /// use storage_data::{StorageData, StorageKind};
+    ///
+    /// StorageKind::Local.set_item("name", || Ok(String::from("Jorge"))).unwrap();
+    /// let name = StorageData::new("name", String::new).lenient_string();
+    /// assert_eq!(name.get(), "Jorge");
+    /// ```
+    ///
+    /// This is opt-in, as it turns values that are actually corrupt into strings rather than
+    /// reporting them, unlike [StorageData::raw_string], which never goes through the serde
+    /// format.
+    pub const fn lenient_string(mut self) -> Self {
+        self.codec.lenient_string = Some(Value::from);
+        self
+    }
+}
+
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + AsRef<[u8]> + From<Vec<u8>>,
+{
+    /// Stores the bytes as a string where every byte is the character of the same code point, as
+    /// in Latin-1, reading them back exactly, rather than serializing them through a serde
+    /// format, where JSON writes an array of numbers and bincode encodes them as base64, this
+    /// keeps small binary blobs, such as icons or tokens, at a single UTF-16 code unit per byte:
+    ///
+    /// ```rust no_run
+    /// use storage_data::StorageData;
+    ///
+    /// let mut icon = StorageData::new("icon", Vec::<u8>::new).raw_bytes();
+    /// icon.set(Vec::from([0x89, b'P', b'N', b'G'])).unwrap();
+    /// ```
+    ///
+    /// The bytes are still compressed, versioned or given an expiry if requested through
+    /// ``compressed``, [StorageData::versioned] or [StorageData::with_ttl].
+    pub const fn raw_bytes(self) -> Self {
+        self.serde_with(serdes::serialize_latin1_bytes, serdes::deserialize_latin1_bytes)
+    }
+}
+
+impl<Key, Item, Backend> StorageData<Key, Vec<Item>, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Item: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Appends the item to the current value, marking it as mutated, so it's saved later, just
+    /// like [Vec::push] through [DerefMut] does.
+    pub fn push(&mut self, item: Item) {
+        self.mutated = true;
+        self.get_mut().push(item);
+    }
+
+    /// Appends the item to the current value and saves it right away, which is useful for lists
+    /// grown once in a while, such as a list of picked products:
+    ///
+    /// ```rust no_run
+    /// use storage_data::StorageData;
+    ///
+    /// let mut picked_products = StorageData::new("picked_products", Vec::<String>::new);
+    /// picked_products.push_and_save(String::from("Keyboard")).unwrap();
+    /// ```
+    ///
+    /// This might fail for the same reasons [StorageData::save] does, in which case the item is
+    /// still appended to the current value.
+    pub fn push_and_save(&mut self, item: Item) -> Result<(), StorageError> {
+        self.push(item);
+        self.save()
+    }
+
+    /// Appends every item to the current value, marking it as mutated, so it's saved later, just
+    /// like [Vec::extend] through [DerefMut] does.
+    pub fn extend<Items: IntoIterator<Item = Item>>(&mut self, items: Items) {
+        self.mutated = true;
+        self.get_mut().extend(items);
+    }
+
+    /// Appends every item to the current value and saves it right away, see
+    /// [StorageData::push_and_save].
+    pub fn extend_and_save<Items: IntoIterator<Item = Item>>(
+        &mut self,
+        items: Items,
+    ) -> Result<(), StorageError> {
+        self.extend(items);
+        self.save()
+    }
+
+    /// Keeps only the items of the current value for which the predicate returns ``true``,
+    /// marking it as mutated, so it's saved later, just like [Vec::retain] through [DerefMut]
+    /// does.
+    pub fn retain<Predicate: FnMut(&Item) -> bool>(&mut self, predicate: Predicate) {
+        self.mutated = true;
+        self.get_mut().retain(predicate);
+    }
+
+    /// Keeps only the items of the current value for which the predicate returns ``true`` and
+    /// saves it right away, see [StorageData::push_and_save].
+    pub fn retain_and_save<Predicate: FnMut(&Item) -> bool>(
+        &mut self,
+        predicate: Predicate,
+    ) -> Result<(), StorageError> {
+        self.retain(predicate);
+        self.save()
+    }
+}
+
+impl<Key, Value, Backend> StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Specifies whether this value is automatically saved when the [StorageData] is drop.
+    ///
+    /// Note: Updates only happen when the value has been updated or if the key isn't present in the
+    /// storage, meaning only the truly necessary saves are executed.
+    pub const fn save_on_drop(mut self, update_on_drop: bool) -> Self {
+        self.save_on_drop = update_on_drop;
+        self
+    }
+
+    /// Rebinds this glue to the key indicated, just like [StorageData::set_key].
+    ///
+    /// An unbound glue got through deserialization, which isn't saved until bound to a key, keeps
+    /// the value it was deserialized with instead, marking it as mutated, as it was meant for the
+    /// key it is now bound to, see the [serde::Deserialize] implementation of [StorageData].
+    pub fn with_key(mut self, key: Key) -> Self {
+        if self.unbound {
+            self.unbound = false;
+            self.key = key;
+            self.mutated = true;
+        } else {
+            self.set_key(key);
+        }
+        self
+    }
+
+    /// Rebinds this glue to the key indicated, clearing its cached value and discarding any
+    /// mutation not yet saved, as they belong to the previous key, so the value is read again
+    /// from the new key the next time it is accessed.
+    pub fn set_key(&mut self, key: Key) {
+        self.unregister_key();
+        self.key = key;
+        self.value = OnceCell::new();
+        self.mutated = false;
+        self.read_mtime.set(None);
+    }
+
+    /// Specifies what this glue does when there is no ``window`` to reach the Storage through,
+    /// such as during server-side rendering or in a worker, by default, it falls back to the
+    /// default value, just like when the key is absent, see [NoWindowPolicy].
+    pub const fn on_no_window(mut self, policy: NoWindowPolicy) -> Self {
+        self.no_window_policy = policy;
+        self
+    }
+
+    /// Specifies how the value is serialized when setting on the Storage.
+    pub const fn serialize_with(
+        mut self,
+        serialize: fn(&Value) -> Result<String, Box<dyn Error>>,
+    ) -> Self {
+        self.codec.serialize_as = serialize;
+        self.codec.serialize_with_closure = false;
+        #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+        {
+            self.codec.serializes_as_base64 = false;
+        }
+        #[cfg(feature = "indexeddb")]
+        {
+            self.codec.binary = None;
+        }
+        self
+    }
+
+    /// Specifies how the value is serialized when setting on the Storage just like
+    /// [StorageData::serialize_with], but accepting closures, which can capture state, such as an
+    /// encryption key or a format chosen at runtime.
+    pub fn serialize_with_fn<Serialize>(mut self, serialize: Serialize) -> Self
+    where
+        Serialize: Fn(&Value) -> Result<String, Box<dyn Error>> + 'static,
+    {
+        self.codec.serialize_closure = Some(Rc::new(serialize));
+        self.codec.serialize_with_closure = true;
+        #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+        {
+            self.codec.serializes_as_base64 = false;
+        }
+        self
+    }
+
+    /// Specifies how the value is deserialized when retrieving it from the Storage.
+    pub const fn deserialize_with(
+        mut self,
+        serialize: fn(String) -> Result<Value, Box<dyn Error>>,
+    ) -> Self {
+        self.codec.deserialize_as = serialize;
+        self.codec.deserialize_with_closure = false;
+        #[cfg(feature = "indexeddb")]
+        {
+            self.codec.binary = None;
+        }
+        self
+    }
+
+    /// Specifies how the value is deserialized when retrieving it from the Storage just like
+    /// [StorageData::deserialize_with], but accepting closures, which can capture state, such as an
+    /// encryption key or a format chosen at runtime.
+    pub fn deserialize_with_fn<Deserialize>(mut self, deserialize: Deserialize) -> Self
+    where
+        Deserialize: Fn(String) -> Result<Value, Box<dyn Error>> + 'static,
+    {
+        self.codec.deserialize_closure = Some(Rc::new(deserialize));
+        self.codec.deserialize_with_closure = true;
+        self
+    }
+
+    /// Changes how the value is serialized when setting on the Storage just like
+    /// [StorageData::serialize_with], but on a glue already in use, such as one inside a struct
+    /// generated through the WebStorage derive macro, which is useful to switch formats after
+    /// construction.
+    ///
+    /// Values are written in the new format from their next write on, while those already
+    /// stored are kept in the format they were written in.
+    pub fn set_serializer(&mut self, serialize: fn(&Value) -> Result<String, Box<dyn Error>>) {
+        self.codec.serialize_as = serialize;
+        self.codec.serialize_with_closure = false;
+        #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+        {
+            self.codec.serializes_as_base64 = false;
+        }
+        #[cfg(feature = "indexeddb")]
+        {
+            self.codec.binary = None;
+        }
+    }
+
+    /// Changes how the value is deserialized when retrieving it from the Storage just like
+    /// [StorageData::deserialize_with], but on a glue already in use, see
+    /// [StorageData::set_serializer].
+    ///
+    /// The value held by this glue is kept, so the new deserializer is only used on the next read
+    /// from the Storage, such as after calling [StorageData::reload].
+    pub fn set_deserializer(&mut self, deserialize: fn(String) -> Result<Value, Box<dyn Error>>) {
+        self.codec.deserialize_as = deserialize;
+        self.codec.deserialize_with_closure = false;
+        #[cfg(feature = "indexeddb")]
+        {
+            self.codec.binary = None;
+        }
+    }
+
+    /// Gets a new default value, the one this glue takes when the Storage doesn't contain its key
+    /// or its value can't be deserialized.
+    pub fn default(&self) -> Value {
+        (self.default_value)()
+    }
+
+    /// Changes the function giving the default value on a glue already in use, such as to choose
+    /// a fallback depending on the user's locale:
+    ///
+    /// ```rust no_run
+    /// use storage_data::StorageData;
+    ///
+    /// let mut date_format = StorageData::new("date_format", || String::from("YYYY-MM-DD"));
+    /// date_format.set_default(|| String::from("DD/MM/YYYY"));
+    /// assert_eq!(date_format.default(), "DD/MM/YYYY");
+    /// ```
+    ///
+    /// The value held by this glue is kept even if it was taken from the previous default, so the
+    /// new default is only used when the value is resolved again, such as after calling
+    /// [StorageData::reload].
+    pub fn set_default(&mut self, default: fn() -> Value) {
+        self.default_value = default;
+    }
+
+    /// Specifies how the value is serialized when setting on the Storage and how it is deserialized
+    /// when retrieving it from the Storage.
+    pub const fn serde_with(
+        self,
+        serialize: fn(&Value) -> Result<String, Box<dyn Error>>,
+        deserialize: fn(String) -> Result<Value, Box<dyn Error>>,
+    ) -> Self {
+        self.serialize_with(serialize).deserialize_with(deserialize)
+    }
+
+    /// Sets serialization and deserialization as JSON's.
+    #[cfg(feature = "use_serde_json")]
+    pub const fn serde_json(self) -> Self {
+        self.serde_with(serdes::serialize_json, serdes::deserialize_json)
+    }
+
+    /// Sets serialization and deserialization as JSON's, just like [StorageData::serde_json], but
+    /// serializing it as indented JSON, which is easier to read and edit by hand through the
+    /// devtools, at the cost of taking more of the Storage's space due to the added whitespace.
+    #[cfg(feature = "use_serde_json")]
+    pub const fn serde_json_pretty(self) -> Self {
+        self.serde_with(serdes::serialize_json_pretty, serdes::deserialize_json)
+    }
+
+    /// Sets serialization and deserialization as bincode's.
+    #[cfg(feature = "use_serde_bincode")]
+    pub const fn serde_bincode(self) -> Self {
+        #[allow(unused_mut)]
+        let mut with_bincode =
+            self.serde_with(serdes::serialize_bincode, serdes::deserialize_bincode);
+        with_bincode.codec.serializes_as_base64 = true;
+        #[cfg(feature = "indexeddb")]
+        {
+            with_bincode.codec.binary =
+                Some((serdes::serialize_bincode_bytes, serdes::deserialize_bincode_bytes));
+        }
+        with_bincode
+    }
+
+    /// Sets serialization and deserialization as YAML's.
+    ///
+    /// Values are always written in block style, where every nested mapping and sequence is
+    /// written on its own indented lines, as that is the only style ``serde_yaml`` writes, so
+    /// values are readable when inspecting them through the devtools; only empty mappings and
+    /// sequences are written in flow style, as ``{}`` and ``[]``, hence there is no separate
+    /// builder forcing block style.
+    #[cfg(feature = "use_serde_yaml")]
+    pub const fn serde_yaml(self) -> Self {
+        self.serde_with(serdes::serialize_yaml, serdes::deserialize_yaml)
+    }
+
+    /// Sets serialization and deserialization as RON's.
+    #[cfg(feature = "use_serde_ron")]
+    pub const fn serde_ron(self) -> Self {
+        self.serde_with(serdes::serialize_ron, serdes::deserialize_ron)
+    }
+
+    /// Sets serialization and deserialization as cbor's.
+    #[cfg(feature = "use_serde_cbor")]
+    pub const fn serde_cbor(self) -> Self {
+        #[allow(unused_mut)]
+        let mut with_cbor = self.serde_with(serdes::serialize_cbor, serdes::deserialize_cbor);
+        with_cbor.codec.serializes_as_base64 = true;
+        #[cfg(feature = "indexeddb")]
+        {
+            with_cbor.codec.binary =
+                Some((serdes::serialize_cbor_bytes, serdes::deserialize_cbor_bytes));
+        }
+        with_cbor
+    }
+
+    /// Stores the bytes of binary serde formats, such as bincode's and CBOR's, as a string where
+    /// every byte is a single character in the Latin-1 range, rather than encoding them as
+    /// base64, which takes a third more space, being applied after serializing and before
+    /// deserializing.
+    ///
+    /// Values stored this way contain control and non-printable characters, so they are no longer
+    /// safe to copy and paste through the devtools, and values stored previously as base64 can't
+    /// be read anymore, so this is only meant for keys this crate alone reads and writes.
+    ///
+    /// This must only be used along with binary serde formats, as the serialized value is
+    /// expected to be base64, so it panics unless the serde format is bincode's or CBOR's, either
+    /// as the default one or set through [StorageData::serde_bincode] or
+    /// [StorageData::serde_cbor] before calling this.
+    #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+    pub const fn binary_as_latin1(mut self) -> Self {
+        assert!(
+            self.codec.serializes_as_base64,
+            "binary_as_latin1 requires the serde format to be bincode's or CBOR's"
+        );
+        self.codec.binary_as_latin1 = true;
+        self
+    }
+
+    /// Compresses the serialized value with DEFLATE before setting it on the Storage, this is
+    /// composed with the serde format currently selected, being applied after serializing and
+    /// before deserializing.
+    ///
+    /// Compressed values are marked with a short prefix, so values written before enabling
+    /// compression are still deserialized correctly, being compressed on their next save.
+    #[cfg(feature = "compress")]
+    pub const fn compressed(mut self) -> Self {
+        self.codec.compressed = true;
+        self
+    }
+
+    /// Marks the values this glue stores with the indicated version, allowing to evolve the type of
+    /// the value across releases without losing the values already stored.
+    ///
+    /// When retrieving a value stored with a different version, the serialized value is first
+    /// upgraded through ``migrate``, which receives the version it was stored with and the
+    /// serialized value, then it's deserialized, and finally it's saved again with the current
+    /// version. Values stored before adopting versioning are considered to be of version 0.
+    pub const fn versioned(mut self, version: u32, migrate: Migration) -> Self {
+        self.codec.version = Some((version, migrate));
+        self
+    }
+
+    /// Makes the value stored in the Storage expire after the indicated amount of milliseconds
+    /// since it was last saved, after which it is removed from the Storage and the default value
+    /// is used instead, this is useful for values working as a cache.
+    pub const fn with_ttl(mut self, ttl_ms: u64) -> Self {
+        self.codec.ttl_ms = Some(ttl_ms);
+        self
+    }
+
+    /// Keeps the indicated amount of previous values in the Storage, every time a new value is
+    /// persisted through [StorageData::set] or [StorageData::save], the value it replaces is pushed
+    /// into a history stored under the keys ``{key}.hist.0`` to ``{key}.hist.{depth - 1}``, being
+    /// ``{key}.hist.0`` the most recent one, and discarding the oldest one once it's full.
+    ///
+    /// These previous values can be retrieved through [StorageData::history] and restored through
+    /// [StorageData::undo], working as an undo stack.
+    pub const fn with_history(mut self, depth: usize) -> Self {
+        self.history_depth = depth;
+        self
+    }
+
+    /// Sets a function resolving conflicts when saving a value that was written to the Storage by
+    /// someone else, such as another tab, after this glue read it, which would otherwise be
+    /// overwritten silently, as the last write always wins.
+    ///
+    /// To detect these writes, the time of the last write is kept under the key ``{key}.mtime``,
+    /// and when [StorageData::save] finds it newer than the time of the value this glue read, the
+    /// function receives this glue's value and the one in the Storage, saving the value it
+    /// returns, which allows merging both values or picking a winner.
+    pub const fn with_on_conflict(mut self, on_conflict: OnConflict<Value>) -> Self {
+        self.on_conflict = Some(on_conflict);
+        self
+    }
+
+    /// Sets a key to read the value from when this glue's key isn't present on the Storage, before
+    /// falling back to the default value, which is useful when renaming a key across versions of
+    /// an app, as values stored under the old key are read by the new one and moved to it once
+    /// saved.
+    ///
+    /// The fallback key is read from the same storage kind and deserialized in the same way as
+    /// this glue's key.
+    pub const fn fallback_key(mut self, fallback_key: &'static str) -> Self {
+        self.fallback_key = Some(fallback_key);
+        self
+    }
+
+    /// Sets whether a value read from the key given through [StorageData::fallback_key] is moved
+    /// right away to this glue's key, removing the fallback key from the Storage, rather than
+    /// leaving the fallback key untouched.
+    pub const fn fallback_key_migrate(mut self, migrate: bool) -> Self {
+        self.migrate_fallback_key = migrate;
+        self
+    }
+
+    /// Defers the writes made through [StorageData::set] by the indicated milliseconds, collapsing
+    /// bursts of writes into a single one, which is useful on interactive UIs, such as a slider
+    /// setting the value dozens of times per second, as every write to the Storage is synchronous.
+    ///
+    /// Every set value is written once no other value has been set for said milliseconds, while
+    /// [StorageData::save], [StorageData::reload] and dropping the glue write the pending value
+    /// right away, and [StorageData::remove] discards it.
+    pub fn debounce_save(mut self, millis: f64) -> Self {
+        self.debounced_save = DebouncedSave::new(millis).ok();
+        self
+    }
+
+    /// Sets a callback invoked with the value every time it is persisted in the Storage through
+    /// [StorageData::set] or [StorageData::save], allowing to react to changes made by this glue,
+    /// such as re-rendering the parts of a UI showing the value.
+    ///
+    /// The callback is only invoked once the value is confirmed to be persisted, so mutating the
+    /// value through [DerefMut] doesn't invoke it until it's saved, and then it's invoked once,
+    /// no matter how many times the value was mutated; likewise, a save that has nothing to write
+    /// doesn't invoke it.
+    pub fn on_change(mut self, callback: OnChange<Value>) -> Self {
+        self.on_change = Some(callback);
+        self
+    }
+
+    /// Listens to changes made to this glue's key from other tabs or windows, invoking the
+    /// callback with the new value every time it changes, or with the default value if the key is
+    /// removed; new values that could not be deserialized are ignored.
+    ///
+    /// The callback is invoked as long as the returned [StorageListener] is kept alive.
+    ///
+    /// Note: This doesn't update the value of this glue, to do so, call [StorageData::reload].
+    pub fn on_external_change<Callback>(
+        &self,
+        callback: Callback,
+    ) -> Result<StorageListener, Box<dyn Error>>
+    where
+        Value: 'static,
+        Callback: Fn(Value) + 'static,
+    {
+        StorageListener::new(
+            self.item_store().web_sys_storage()?,
+            self.key.as_ref(),
+            None,
+            listener::new_value,
+            listener::deserialize_changes(self.codec.clone(), self.default_value, callback),
+        )
+    }
+
+    /// Listens to changes made to this glue's key from other tabs or windows just like
+    /// [StorageData::on_external_change], but invoking the callback with every detail of the
+    /// change, such as the serialized value the key had before it, and the URL of the document
+    /// that made it, allowing to decide whether the change is worth a reload:
+    ///
+    /// ```rust no_run
+    /// use storage_data::StorageData;
+    ///
+    /// let theme = StorageData::new("theme", || String::from("light"));
+    /// let _listener = theme.on_external_change_detailed(|change| {
+    ///     if change.old_value != change.new_value {
+    ///         if let Ok(theme) = change.value() {
+    ///             println!("{} changed the theme to {theme}", change.url);
+    ///         }
+    ///     }
+    /// });
+    /// ```
+    ///
+    /// The new value is only deserialized when asked for through [ExternalChange::value].
+    pub fn on_external_change_detailed<Callback>(
+        &self,
+        callback: Callback,
+    ) -> Result<StorageListener, Box<dyn Error>>
+    where
+        Value: 'static,
+        Callback: Fn(ExternalChange<Value>) + 'static,
+    {
+        let codec = self.codec.clone();
+        let default_value = self.default_value;
+        StorageListener::new(
+            self.item_store().web_sys_storage()?,
+            self.key.as_ref(),
+            None,
+            move |event| ExternalChange::new(event, codec.clone(), default_value),
+            callback,
+        )
+    }
+
+    /// Listens to changes made to this glue's key from other tabs or windows just like
+    /// [StorageData::on_external_change], but bursts of changes are coalesced, invoking the
+    /// callback once no change has been made for the indicated milliseconds, and only with the
+    /// latest value.
+    ///
+    /// This is useful for values changing at a high frequency, such as those of a slider, which
+    /// would otherwise invoke the callback for every change.
+    pub fn on_external_change_debounced<Callback>(
+        &self,
+        millis: u32,
+        callback: Callback,
+    ) -> Result<StorageListener, Box<dyn Error>>
+    where
+        Value: 'static,
+        Callback: Fn(Value) + 'static,
+    {
+        StorageListener::new(
+            self.item_store().web_sys_storage()?,
+            self.key.as_ref(),
+            Some(i32::try_from(millis).unwrap_or(i32::MAX)),
+            listener::new_value,
+            listener::deserialize_changes(self.codec.clone(), self.default_value, callback),
+        )
+    }
+
+    /// Gets a [StorageWatch], a stream yielding the value every time this glue persists it, just
+    /// like the callback set through [StorageData::on_change], and every time it's changed from
+    /// other tabs or windows, just like [StorageData::on_external_change], which is useful to
+    /// bridge the value into the signals of UI frameworks:
+    ///
+    /// ```rust no_run
+    /// use futures_util::StreamExt;
+    /// use storage_data::StorageData;
+    ///
+    /// # async fn render_theme() {
+    /// let mut theme = StorageData::new("theme", || String::from("light"));
+    /// let mut theme_changes = theme.watch().unwrap();
+    /// theme.set(String::from("dark")).unwrap();
+    /// assert_eq!(theme_changes.next().await.unwrap(), "dark");
+    /// # }
+    /// ```
+    ///
+    /// The stream ends once this glue is dropped, when the listener to other tabs is removed too.
+    ///
+    /// This fails if changes from other tabs can't be listened to, such as for cookies, returning
+    /// an explanation to this through an ``Err<Box<dyn Error>>``.
+    #[cfg(feature = "watch")]
+    pub fn watch(&mut self) -> Result<StorageWatch<Value>, Box<dyn Error>>
+    where
+        Value: Clone + 'static,
+    {
+        let channel = watch::WatchChannel::new();
+        let external_channel = channel.clone();
+        let listener = self.on_external_change(move |value| {
+            watch::WatchChannel::send(&external_channel, value);
+        })?;
+        let local_channel = channel.clone();
+        let previous_on_change = self.on_change.take();
+        self.on_change = Some(Rc::new(move |value: &Value| {
+            if let Some(previous_on_change) = &previous_on_change {
+                previous_on_change(value);
+            }
+            watch::WatchChannel::send(&local_channel, value.clone());
+        }));
+        self.watchers.push((channel.clone(), listener));
+        Ok(StorageWatch::new(channel))
+    }
+
+    /// Gets the current value, if is not set, it retrieves it from the Storage through a
+    /// deserialization, and if not present, it gets it as the default value.
+    fn resolve(&self) -> &Value {
+        self.value.get_or_init(|| self.load())
+    }
+
+    /// Storage this glue reads and writes its items from, see [StorageData::with_storage_object].
+    fn item_store(&self) -> ItemStore<'_> {
+        ItemStore::new(&self.storage_kind, self.storage_object.as_ref())
+    }
+
+    /// Tells whether the Storage needs a ``window`` to be reached, while there is none, which
+    /// never happens once a Storage object is given.
+    fn window_is_missing(&self) -> bool {
+        self.storage_object.is_none() && self.storage_kind.window_is_missing()
+    }
+
+    /// Registers the key of this glue as used by a live glue once it's first used, warning if
+    /// another live glue already uses it, this only happens on debug builds, as glues can't be
+    /// registered when created, since they're created on const contexts.
+    fn register_key(&self) {
+        #[cfg(debug_assertions)]
+        if !self.registered_key.replace(true) {
+            key_registry::register(self.storage_kind, self.key.as_ref());
+        }
+    }
+
+    /// Unregisters the key of this glue as used by a live glue, so it can be used by another
+    /// one, this only happens on debug builds, see [StorageData::register_key].
+    fn unregister_key(&self) {
+        #[cfg(debug_assertions)]
+        if self.registered_key.replace(false) {
+            key_registry::unregister(self.storage_kind, self.key.as_ref());
+        }
+    }
+
+    /// Retrieves the value from the Storage through a deserialization, and if not present, it
+    /// gets it as the default value, without caching it.
+    fn load(&self) -> Value {
+        self.try_load().unwrap_or_else(|_| (self.default_value)())
+    }
+
+    /// Retrieves the value from the Storage just like ``load``, but failing if the
+    /// Storage could not be read, rather than getting the default value as if the key was absent.
+    fn try_load(&self) -> Result<Value, Box<dyn Error>> {
+        if self.no_window_policy == NoWindowPolicy::Panic && self.window_is_missing() {
+            panic!("{NO_WINDOW}");
+        }
+        match (self.load_stored()?, self.panic_on_cannot_deserialize) {
+            (Ok(value), _) => Ok(value),
+            (Err(_), false) => Ok((self.default_value)()),
+            (Err(error), true) => panic!("{error}"),
+        }
+    }
+
+    /// Retrieves the value from the Storage just like ``try_load``, but never panicking, failing
+    /// if the Storage could not be read, and giving the reason the stored value is corrupt rather
+    /// than panicking over it.
+    fn load_stored(&self) -> Result<Result<Value, Box<dyn Error>>, Box<dyn Error>> {
+        self.register_key();
+        if self.on_conflict.is_some() {
+            self.read_mtime.set(self.stored_mtime());
+        }
+        let key = self.key.as_ref();
+        let mut read = self.item_store().get_item(key);
+        let stored_value_expired = self.codec.ttl_ms.is_some()
+            && matches!(&read, Ok(Some(serialized)) if self.codec.expired(serialized));
+        if stored_value_expired {
+            let _ = self.item_store().remove_item(key);
+            read = Ok(None);
+        }
+        let migrated = core::cell::Cell::new(false);
+        let deserialize = |serialized| {
+            let (value, was_migrated) = self.codec.deserialize(serialized)?;
+            migrated.set(was_migrated);
+            Ok(value)
+        };
+        let mut loaded = parse_loaded(read, key, deserialize);
+        if let (Loaded::Absent, Some(fallback_key)) = (&loaded, self.fallback_key) {
+            let read = self.item_store().get_item(fallback_key);
+            loaded = parse_loaded(read, fallback_key, deserialize);
+            if let (Loaded::Parsed(_), true) = (&loaded, self.migrate_fallback_key) {
+                migrated.set(true);
+                let _ = self.item_store().remove_item(fallback_key);
+            }
+        }
+        let value = match loaded {
+            Loaded::Absent => Ok((self.default_value)()),
+            Loaded::Unavailable(error) => return Err(error),
+            Loaded::Parsed(value) => Ok(value),
+            Loaded::Corrupt { error, .. } => Err(error),
+        };
+        if let (Ok(value), true) = (&value, migrated.get()) {
+            let _ = set_data_on(self.item_store(), self.key.as_ref(), value, |value| {
+                self.codec.serialize(value)
+            });
+        }
+        Ok(value)
+    }
+
+    /// Gets the current value from the glue just like [StorageData::get], but retrieving it
+    /// asynchronously, which is required for [StorageKind::IndexedDb], while for other storage
+    /// kinds it's equivalent to [StorageData::get].
+    ///
+    /// Unlike [StorageData::get], this fails rather than panicking if the value could not be
+    /// deserialized, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    #[cfg(feature = "indexeddb")]
+    pub async fn get_async(&self) -> Result<&Value, Box<dyn Error>> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        let value = match self.storage_kind.get_payload_async(self.key.as_ref()).await? {
+            Some(payload) => self.codec.deserialize_payload(payload)?.0,
+            None => (self.default_value)(),
+        };
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Sets the value for both this glue and the Storage just like [StorageData::set], but writing
+    /// it asynchronously, which is required for [StorageKind::IndexedDb].
     ///
     /// Saving the result in the Storage might fail, for example, if the value could not be
-    /// serialized, or if the quota's limit is reached, returning an explanation to this through an
+    /// serialized, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    #[cfg(feature = "indexeddb")]
+    pub async fn set_async(&mut self, value: Value) -> Result<(), Box<dyn Error>> {
+        let payload = self.codec.serialize_payload(&value)?;
+        self.storage_kind.set_payload_async(self.key.as_ref(), payload).await?;
+        if let Some(on_change) = &self.on_change {
+            on_change(&value);
+        }
+        self.value = OnceCell::with_value(value);
+        self.mutated = false;
+        Ok(())
+    }
+
+    /// Saves the current value in the glue over the Storage just like [StorageData::save], but
+    /// writing it asynchronously, which is required for [StorageKind::IndexedDb], as values of
+    /// this storage kind aren't saved when the glue is dropped.
+    ///
+    /// Only values that were mutated are saved, and saving them might fail, for example, if the
+    /// value could not be serialized, returning an explanation to this through an
     /// ``Err<Box<dyn Error>>``.
-    pub fn set(&mut self, value: Value) -> Result<(), Box<dyn Error>> {
-        let res = set_data(&self.storage_kind, &self.key, &value, self.serialize_as);
+    #[cfg(feature = "indexeddb")]
+    pub async fn save_async(&mut self) -> Result<(), Box<dyn Error>> {
+        let Some(value) = self.value.get().filter(|_| self.mutated) else {
+            return Ok(());
+        };
+        let payload = self.codec.serialize_payload(value)?;
+        self.storage_kind.set_payload_async(self.key.as_ref(), payload).await?;
+        self.mutated = false;
+        if let Some(on_change) = &self.on_change {
+            on_change(self.resolve());
+        }
+        Ok(())
+    }
+
+    /// Gets the current value from the glue just like [StorageData::get], but failing if there is
+    /// no ``window`` to reach the Storage through when using [NoWindowPolicy::Error], or if the
+    /// Storage could not be read, such as when the web browser blocks it on private browsing,
+    /// telling apart a key being absent from the Storage not being reachable at all.
+    pub fn try_get(&self) -> Result<&Value, Box<dyn Error>> {
+        if let Some(value) = self.value.get() {
+            return Ok(value);
+        }
+        if self.window_is_missing() {
+            return match self.no_window_policy {
+                NoWindowPolicy::Error => None.map_log_possible_error(|_| NO_WINDOW),
+                _ => Ok(self.resolve()),
+            };
+        }
+        let value = self.try_load()?;
+        Ok(self.value.get_or_init(|| value))
+    }
+
+    /// Gets the [Display] output of the current value, or the indicated fallback if the stored
+    /// value is corrupt or the Storage could not be read, never panicking, unlike rendering the
+    /// glue through its [Display] or [Debug] implementations, which makes it safe to show glues
+    /// on a UI.
+    ///
+    /// ```no_run
+    /// # use storage_data::StorageData;
+    /// let volume = StorageData::new("volume", || 50_u8);
+    /// println!("Volume: {}", volume.display_or("—"));
+    /// ```
+    pub fn display_or<'a>(&'a self, fallback: &'a str) -> Cow<'a, str>
+    where
+        Value: Display,
+    {
+        let value = match self.value.get() {
+            Some(value) => value,
+            None => match self.load_stored() {
+                Ok(Ok(value)) => self.value.get_or_init(|| value),
+                _ => return Cow::Borrowed(fallback),
+            },
+        };
+        Cow::Owned(format!("{value}"))
+    }
+
+    /// Gets the current value from the glue.
+    ///
+    /// This might not be up to date with the Storage if the Storage is modified outside this
+    /// [StorageData].
+    pub fn get(&self) -> &Value {
+        self.resolve()
+    }
+
+    /// Gets the current value from the glue.
+    ///
+    /// This might not be up to date with the Storage if the Storage is modified outside this
+    /// [StorageData].
+    pub fn get_mut(&mut self) -> &mut Value {
+        if self.value.get().is_none() {
+            self.value = OnceCell::with_value(self.load());
+        }
+        let default_value = self.default_value;
+        self.value
+            .get_mut()
+            .unwrap_or_else(|| Box::leak(Box::new(default_value())))
+    }
+
+    /// Reads and deserializes the value straight from the Storage on every call, without caching
+    /// it nor altering the value held by this glue, returning ``None`` if the key isn't present
+    /// or if its value has expired, this is useful for polling the Storage for changes made outside
+    /// this [StorageData], such as those made by other tabs.
+    ///
+    /// This might fail if the Storage could not be read or if the value could not be deserialized,
+    /// returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    pub fn peek(&self) -> Result<Option<Value>, Box<dyn Error>> {
+        match self.item_store().get_item(self.key.as_ref())? {
+            Some(serialized) if !self.codec.expired(&serialized) => {
+                Ok(Some(self.codec.deserialize(serialized)?.0))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Gets a guard over the current value which saves it once the guard is dropped, but only if
+    /// it was mutated through it, see [StorageEntry].
+    pub fn entry(&mut self) -> StorageEntry<'_, Key, Value, Backend> {
+        StorageEntry::new(self)
+    }
+
+    /// Gets a part of the current value, such as one of its fields, through a call to
+    /// [StorageData::get], this is useful for big values where only a part of it is relevant, as
+    /// in ``settings.project(|settings| &settings.theme.color)``.
+    pub fn project<'a, T>(&'a self, project: impl Fn(&'a Value) -> &'a T) -> &'a T {
+        project(self.get())
+    }
+
+    /// Gets a mutable part of the current value, such as one of its fields, through a call to
+    /// [StorageData::get_mut], marking the value as mutated just like [DerefMut] does, so it's
+    /// saved later.
+    pub fn project_mut<'a, T>(
+        &'a mut self,
+        project: impl Fn(&'a mut Value) -> &'a mut T,
+    ) -> &'a mut T {
+        self.mutated = true;
+        project(self.get_mut())
+    }
+
+    /// Sets the value for both this glue and the Storage, meaning the Storage should be up to date
+    /// after calling this function.
+    ///
+    /// Saving the result in the Storage might fail, for example, if the value could not be
+    /// serialized, or if the quota's limit is reached, returning an explanation to this through a
+    /// [StorageError].
+    pub fn set(&mut self, value: Value) -> Result<(), StorageError> {
+        self.try_set(value)
+    }
+
+    /// Sets the value for both this glue and the Storage just like [StorageData::set], but telling
+    /// apart the reason of the failure through a [StorageError], so the Storage being full can be
+    /// handled differently than other failures, such as by prompting the user to free space.
+    pub fn try_set(&mut self, value: Value) -> Result<(), StorageError> {
+        self.register_key();
+        if let Some(idle_save) = &self.idle_save {
+            idle_save.cancel();
+        }
+        let key = self.key.as_ref();
+        let write_is_pending = self
+            .debounced_save
+            .as_ref()
+            .is_some_and(DebouncedSave::is_pending);
+        let rotated = match write_is_pending {
+            true => Ok(()),
+            false => self.rotate_history().map_err(StorageError::StorageUnavailable),
+        };
+        let res = rotated
+            .and_then(|()| {
+                self.serialize(&value)
+                    .log_possible_error(|error| {
+                        format!("Could not serialize item for key {key} due to:\n{error:?}")
+                    })
+                    .map_err(StorageError::SerdeSerialize)
+            })
+            .and_then(|serialized| match &self.debounced_save {
+                Some(debounced_save) => debounced_save
+                    .schedule(self.item_store(), key, serialized)
+                    .map_err(StorageError::StorageUnavailable),
+                None => self.item_store().try_set_item(key, serialized),
+            });
+        if res.is_ok() {
+            self.touch_mtime();
+        }
+        if let (Ok(()), Some(on_change)) = (&res, &self.on_change) {
+            on_change(&value);
+        }
         let couldnt_set_and_it_was_initialized =
             self.value.set(value).is_err() && self.value.get().is_some();
         if couldnt_set_and_it_was_initialized {
@@ -441,28 +1964,362 @@ where
         res
     }
 
+    /// Discards the value held by this glue, including any mutation that wasn't saved, and
+    /// retrieves it again from the Storage, this is useful when the Storage is modified outside
+    /// this [StorageData], such as when another tab modifies it.
+    pub fn reload(&mut self) {
+        for deferred_save in self.deferred_saves() {
+            let _ = deferred_save.flush();
+        }
+        self.value = OnceCell::new();
+        self.mutated = false;
+        self.resolve();
+    }
+
+    /// Discards the value held by this glue without removing it from the Storage nor saving it,
+    /// freeing the memory it takes, such as that of a big deserialized struct, while the next
+    /// access retrieves it again from the Storage, unlike [StorageData::reload], which retrieves
+    /// it right away.
+    ///
+    /// Any mutation that wasn't saved is lost, so [StorageData::save] should be called first if
+    /// it must be kept, while values set through [StorageData::set] whose write is still
+    /// deferred, such as through [StorageData::debounce_save], are written right away.
+    pub fn clear_cache(&mut self) {
+        for deferred_save in self.deferred_saves() {
+            let _ = deferred_save.flush();
+        }
+        self.value = OnceCell::new();
+        self.mutated = false;
+    }
+
+    /// Turns this glue into one over the same key holding another type, converting its current
+    /// value, including mutations yet to be saved, through the function given, which helps when
+    /// the type stored on a key evolves in ways serde can't read on its own, unlike
+    /// [StorageData::versioned], which upgrades the serialized value instead.
+    ///
+    /// The new glue is marked as mutated, so the converted value is written on its first save,
+    /// which happens on drop unless [StorageData::save_on_drop] is disabled, while this glue
+    /// won't save its value anymore. The Storage kind and object, and the drop and missing window
+    /// behaviours are kept, but everything else, such as the serde format, takes its default,
+    /// while the default value of the new glue is the one given.
+    ///
+    /// ```no_run
+    /// # use storage_data::StorageData;
+    /// let volume = StorageData::new("volume", || 50_u8);
+    /// let volume = volume.reinterpret(|volume| f32::from(volume) / 100.0, || 0.5);
+    /// assert_eq!(*volume, 0.5);
+    /// ```
+    ///
+    /// The key is cloned, as this glue still needs it when dropped.
+    pub fn reinterpret<U>(
+        mut self,
+        convert: impl FnOnce(Value) -> U,
+        default: fn() -> U,
+    ) -> StorageData<Key, U, Backend>
+    where
+        Key: Clone,
+        U: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    {
+        self.resolve();
+        let value = self.value.take().unwrap_or_else(|| (self.default_value)());
+        let mut reinterpreted = StorageData::with_backend(self.key.clone(), default);
+        reinterpreted.storage_kind = self.storage_kind;
+        reinterpreted.storage_object = self.storage_object.take();
+        reinterpreted.no_window_policy = self.no_window_policy;
+        reinterpreted.save_on_drop = core::mem::replace(&mut self.save_on_drop, false);
+        self.mutated = false;
+        reinterpreted.value = OnceCell::from(convert(value));
+        reinterpreted.mutated = true;
+        reinterpreted
+    }
+
+    /// Checks whether the value stored in the Storage can be deserialized, and if it's corrupt,
+    /// it's replaced with the default value, both in this glue and the Storage, so later reads
+    /// always find a valid value, returning whether said repair happened.
+    ///
+    /// This might fail if the Storage could not be read or if the default value could not be
+    /// saved, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    pub fn verify_and_repair(&mut self) -> Result<bool, Box<dyn Error>> {
+        let Some(serialized) = self.item_store().get_item(self.key.as_ref())? else {
+            return Ok(false);
+        };
+        if self.codec.deserialize(serialized).is_ok() {
+            return Ok(false);
+        }
+        self.set((self.default_value)())?;
+        Ok(true)
+    }
+
+    /// Gets the previous values kept through [StorageData::with_history], from the most recent to
+    /// the oldest one.
+    ///
+    /// This might fail if the Storage could not be read or if any of the values could not be
+    /// deserialized, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    pub fn history(&self) -> Result<Vec<Value>, Box<dyn Error>> {
+        let mut history = Vec::new();
+        for index in 0..self.history_depth {
+            let Some(serialized) = self.item_store().get_item(&self.history_key(index))? else {
+                break;
+            };
+            history.push(self.codec.deserialize(serialized)?.0);
+        }
+        Ok(history)
+    }
+
+    /// Restores the most recent value kept through [StorageData::with_history], both in this
+    /// glue and the Storage, removing it from the history, and returns whether there was a value to
+    /// restore.
+    ///
+    /// Any mutation of this glue that wasn't saved is discarded, just like any write still
+    /// deferred, such as through [StorageData::debounce_save], so it can't overwrite the restored
+    /// value later, and the replaced value isn't pushed into the history, as otherwise undoing
+    /// twice would restore it back.
+    ///
+    /// This might fail if the Storage could not be read or written, returning an explanation to
+    /// this through an ``Err<Box<dyn Error>>``.
+    pub fn undo(&mut self) -> Result<bool, Box<dyn Error>> {
+        if self.history_depth == 0 {
+            return Ok(false);
+        }
+        let Some(previous) = self.item_store().get_item(&self.history_key(0))? else {
+            return Ok(false);
+        };
+        for deferred_save in self.deferred_saves() {
+            deferred_save.cancel();
+        }
+        // Setting the item also discards the write of this key waiting in the write queue.
+        self.item_store().set_item(self.key.as_ref(), || Ok(previous))?;
+        for index in 1..self.history_depth {
+            self.move_item(&self.history_key(index), &self.history_key(index - 1))?;
+        }
+        self.item_store()
+            .remove_item(&self.history_key(self.history_depth - 1))?;
+        self.value = OnceCell::new();
+        self.mutated = false;
+        if let Some(on_change) = &self.on_change {
+            on_change(self.resolve());
+        }
+        Ok(true)
+    }
+
+    /// Serializes the value through the codec, verifying it deserializes back into itself if
+    /// enabled through [StorageData::verify_roundtrip].
+    fn serialize(&self, value: &Value) -> Result<String, Box<dyn Error>> {
+        let serialized = self.codec.serialize(value)?;
+        if let Some(verify_roundtrip) = self.verify_roundtrip {
+            verify_roundtrip(&self.codec, value, &serialized)?;
+        }
+        Ok(serialized)
+    }
+
+    /// Key under which the time of the last write is stored when resolving conflicts.
+    fn mtime_key(&self) -> String {
+        format!("{}.mtime", self.key.as_ref())
+    }
+
+    /// Time of the last write to the Storage, if conflicts are resolved and it was ever written.
+    fn stored_mtime(&self) -> Option<u64> {
+        self.item_store()
+            .get_item(&self.mtime_key())
+            .ok()
+            .flatten()
+            .and_then(|mtime| mtime.parse().ok())
+    }
+
+    /// Records the current time as the time of the last write, if conflicts are resolved.
+    fn touch_mtime(&self) {
+        if self.on_conflict.is_none() {
+            return;
+        }
+        let now = now_ms() as u64;
+        if self
+            .item_store()
+            .set_item(&self.mtime_key(), || Ok(format!("{now}")))
+            .is_ok()
+        {
+            self.read_mtime.set(Some(now));
+        }
+    }
+
+    /// Key under which the value at the indicated position of the history is stored.
+    fn history_key(&self, index: usize) -> String {
+        format!("{}.hist.{index}", self.key.as_ref())
+    }
+
+    /// Pushes the value currently in the Storage into the history, if history is kept.
+    fn rotate_history(&self) -> Result<(), Box<dyn Error>> {
+        if self.history_depth == 0 {
+            return Ok(());
+        }
+        match self.item_store().get_item(self.key.as_ref())? {
+            Some(current) => self.push_history(current),
+            None => Ok(()),
+        }
+    }
+
+    /// Pushes the indicated serialized value into the history, if history is kept, shifting the
+    /// older ones and dropping the oldest.
+    fn push_history(&self, current: String) -> Result<(), Box<dyn Error>> {
+        if self.history_depth == 0 {
+            return Ok(());
+        }
+        for index in (1..self.history_depth).rev() {
+            self.move_item(&self.history_key(index - 1), &self.history_key(index))?;
+        }
+        self.item_store().set_item(&self.history_key(0), || Ok(current))
+    }
+
+    /// Moves the serialized value of a key into another, removing the latter if the former is
+    /// absent.
+    fn move_item(&self, from: &str, to: &str) -> Result<(), Box<dyn Error>> {
+        match self.item_store().get_item(from)? {
+            Some(serialized) => self.item_store().set_item(to, || Ok(serialized)),
+            None => self.item_store().remove_item(to),
+        }
+    }
+
+    /// Kind of storage this glue targets to.
+    pub const fn storage_kind(&self) -> &StorageKind {
+        &self.storage_kind
+    }
+
+    /// Amount of keys in the Storage this glue uses, see [StorageKind::len].
+    pub fn raw_entry_count(&self) -> Result<usize, Box<dyn Error>> {
+        self.item_store().len()
+    }
+
+    /// Estimated amount of bytes the Storage takes to keep this glue's key and its stored value,
+    /// or ``None`` if the key isn't present, this is useful to warn users approaching the
+    /// Storage's quota.
+    ///
+    /// As web browsers keep Local and Session Storage strings as UTF-16, this counts two bytes per
+    /// UTF-16 code unit rather than the length of the UTF-8 string, noting the value might not be
+    /// saved yet if it was mutated.
+    pub fn size_bytes(&self) -> Result<Option<usize>, Box<dyn Error>> {
+        let key = self.key.as_ref();
+        Ok(self.item_store().get_item(key)?.map(|serialized| {
+            (key.encode_utf16().count() + serialized.encode_utf16().count()) * 2
+        }))
+    }
+
+    /// Gets the exact string stored under this glue's key without deserializing it, or ``None`` if
+    /// the key isn't present, this is useful to diagnose values that won't load, such as a value
+    /// written as JSON while the glue is configured for RON.
+    ///
+    /// This reads the Storage directly, so it neither touches the cached value nor marks it as
+    /// mutated, and it shows the stored string as is, including any version or expiry envelope.
+    pub fn inspect_raw(&self) -> Result<Option<String>, Box<dyn Error>> {
+        self.item_store().get_item(self.key.as_ref())
+    }
+
+    /// Gets a view of this glue whose equality and hash are those of its key, rather than those of
+    /// its value, allowing to keep glues on sets and maps by identity, such as on a registry of
+    /// every active setting.
+    pub fn by_key(&self) -> ByKey<'_> {
+        ByKey(self.key.as_ref())
+    }
+
+    /// Tells whether the value of this glue was mutated and not saved yet, which is useful to
+    /// warn about unsaved changes.
+    pub const fn is_dirty(&self) -> bool {
+        self.mutated
+    }
+
+    /// Marks the value of this glue as not mutated without saving it, so it isn't saved when the
+    /// glue is dropped, which is useful when the user explicitly discards their changes.
+    ///
+    /// Note: The value held by this glue keeps the mutations, to restore the one in the Storage,
+    /// call [StorageData::reload].
+    pub fn mark_clean(&mut self) {
+        self.mutated = false;
+    }
+
     /// Tells whether this glue holds a value or the Storage has the key.
     pub fn is_set(&self) -> bool {
-        self.value.get().is_some() || self.storage_kind.get_item(self.key.as_ref()).is_ok()
+        self.value.get().is_some()
+            || self
+                .item_store()
+                .get_item(self.key.as_ref())
+                .is_ok_and(|serialized| serialized.is_some())
     }
 
     /// Removes both the value of the glue and the value in the Storage.
     ///
     /// This might fail for a variety of reasons, returning an explanation through
-    /// an ``Err<Box<dyn Error>>``.
-    pub fn remove(&mut self) -> Result<(), Box<dyn Error>> {
-        self.storage_kind.remove_item(self.key.as_ref())?;
+    /// a [StorageError].
+    pub fn remove(&mut self) -> Result<(), StorageError> {
+        for deferred_save in self.deferred_saves() {
+            deferred_save.cancel();
+        }
+        self.item_store()
+            .remove_item(self.key.as_ref())
+            .map_err(StorageError::StorageUnavailable)?;
         self.finalize_use(true, false);
         Ok(())
     }
 
+    /// Converts the glue into its value as an owned value, this is equivalent to
+    /// [StorageData::take], but reads better at the end of a chain of calls.
+    ///
+    /// ``From<StorageData<Key, Value>> for Value`` can't be implemented, as Rust's orphan rules
+    /// forbid implementing foreign traits for a type parameter such as ``Value``, so this is the
+    /// way of converting a glue into its value:
+    ///
+    /// ```rust no_run
+    /// use storage_data::StorageData;
+    ///
+    /// let visited_times: usize = StorageData::new("visited_times", || 0).into_value();
+    /// ```
+    pub fn into_value(self) -> Value {
+        self.take()
+    }
+
+    /// Takes the value of the glue as an owned value just like [StorageData::take], but without
+    /// consuming the glue, which is useful for glues inside a struct generated through the
+    /// WebStorage derive macro.
+    ///
+    /// Unlike [StorageData::take], which saves the value before handing it, this removes the key
+    /// from the Storage, leaving the glue empty and reusable, so later reads get the default value
+    /// until a new one is set; any mutation that wasn't saved is kept in the returned value.
+    pub fn take_value(&mut self) -> Value {
+        let value = self.value.take().unwrap_or_else(|| self.load());
+        for deferred_save in self.deferred_saves() {
+            deferred_save.cancel();
+        }
+        let _ = self.item_store().remove_item(self.key.as_ref());
+        self.mutated = false;
+        value
+    }
+
     /// Takes the value of the glue as an owned value, and if not set, it gets it
     /// from the Storage, and in case the key isn't present in the Storage, it returns
     /// the default value.
     pub fn take(mut self) -> Value {
         self.finalize_use(false, true);
-        self.resolve();
-        self.value.take().unwrap_or_else(self.default_value)
+        self.value.take().unwrap_or_else(|| self.load())
+    }
+
+    /// Takes the value of the glue as an owned value just like [StorageData::take], but removing
+    /// the key from the Storage rather than saving it, consuming the value for good, which is
+    /// useful for one-shot values, such as an action left pending before a redirect:
+    ///
+    /// ```rust no_run
+    /// use storage_data::{StorageData, StorageKind};
+    ///
+    /// let pending_action = StorageData::new("pending_action", || None::<String>);
+    /// if let Some(pending_action) = pending_action.take_and_remove() {
+    ///     println!("Resuming {pending_action}");
+    /// }
+    /// // Unlike with take, the key is gone, so the action only runs once.
+    /// assert_eq!(StorageKind::Local.get_item("pending_action").unwrap(), None);
+    /// ```
+    ///
+    /// Any mutation that wasn't saved is kept in the returned value, see
+    /// [StorageData::take_value].
+    pub fn take_and_remove(mut self) -> Value {
+        let value = self.take_value();
+        self.save_on_drop = false;
+        value
     }
 
     /// Sets the current value in the glue over the Storage, meaning the Storage
@@ -470,24 +2327,152 @@ where
     ///
     /// Saving the result in the Storage might fail, for example, if the value
     /// could not be serialized, or if the quota's limit is reached, returning
-    /// an explanation to this through an ``Err<Box<dyn Error>>``.
-    pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+    /// an explanation to this through a [StorageError].
+    ///
+    /// Unbound glues got through deserialization aren't saved until bound to a key, see
+    /// [StorageData::with_key].
+    pub fn save(&mut self) -> Result<(), StorageError> {
+        if self.unbound {
+            return Ok(());
+        }
+        for deferred_save in self.deferred_saves() {
+            deferred_save.flush().map_err(StorageError::StorageUnavailable)?;
+        }
         let was_changed = self.mutated && self.value.get().is_some();
-        let storage_contains_this_key = self.storage_kind.get_item(self.key.as_ref()).is_ok();
-        if !was_changed && storage_contains_this_key {
+        if !was_changed && matches!(self.item_store().get_item(self.key.as_ref()), Ok(Some(_))) {
             return Ok(());
         }
-        let res = set_data(
-            &self.storage_kind,
-            &self.key,
-            self.resolve(),
-            self.serialize_as,
-        );
+        // The stored value is read at most once, as both the conflict resolution and the history
+        // need it, while plain saves don't read it at all.
+        let on_conflict = self
+            .on_conflict
+            .filter(|_| self.stored_mtime() > self.read_mtime.get());
+        let stored = if on_conflict.is_some() || self.history_depth > 0 {
+            self.item_store()
+                .get_item(self.key.as_ref())
+                .map_err(StorageError::StorageUnavailable)?
+        } else {
+            None
+        };
+        if let Some(on_conflict) = on_conflict {
+            let stored = stored
+                .clone()
+                .and_then(|serialized| self.codec.deserialize(serialized).ok());
+            if let Some((stored, _)) = stored {
+                let resolved = on_conflict(self.resolve(), &stored);
+                self.value = OnceCell::with_value(resolved);
+            }
+        }
+        if let Some(stored) = stored {
+            self.push_history(stored).map_err(StorageError::StorageUnavailable)?;
+        }
+        let is_default = self
+            .prune_defaults
+            .is_some_and(|is_default| is_default(self.resolve(), self.default_value));
+        let res = if is_default {
+            self.item_store()
+                .remove_item(self.key.as_ref())
+                .map_err(StorageError::StorageUnavailable)
+        } else {
+            set_data_on(self.item_store(), self.key.as_ref(), self.resolve(), |value| {
+                self.serialize(value)
+            })
+        };
         if res.is_ok() {
             self.mutated = false;
+            self.touch_mtime();
+            if let Some(on_change) = &self.on_change {
+                on_change(self.resolve());
+            }
         };
         res
     }
+
+    /// Saves the current value just like [StorageData::save], but retrying the write when the
+    /// Storage is full, as some web browsers free space over time, such as when another tab
+    /// removes values, so apps writing large caches can ride out transient quota pressure.
+    ///
+    /// The value is saved up to ``attempts`` times, waiting through ``setTimeout`` before every
+    /// retry, starting at 100 milliseconds and doubling the wait each time, giving up with the
+    /// last error once every attempt failed, while errors other than
+    /// [StorageError::QuotaExceeded] are returned right away, as retrying won't fix them.
+    ///
+    /// ```rust no_run
+    /// use storage_data::{StorageData, StorageError};
+    ///
+    /// async fn cache_catalog(catalog: Vec<String>) -> Result<(), StorageError> {
+    ///     let mut cache = StorageData::new("catalog", Vec::<String>::new);
+    ///     *cache = catalog;
+    ///     cache.save_with_retry(5).await
+    /// }
+    /// ```
+    #[cfg(feature = "save_retry")]
+    pub async fn save_with_retry(&mut self, attempts: u32) -> Result<(), StorageError> {
+        let mut backoff = save_retry::INITIAL_BACKOFF_MILLIS;
+        let mut res = self.save();
+        for _ in 1..attempts {
+            if !matches!(res, Err(StorageError::QuotaExceeded)) {
+                break;
+            }
+            save_retry::sleep(backoff).await;
+            backoff = backoff.saturating_mul(2);
+            res = self.save();
+        }
+        res
+    }
+    /// Saves the current value just like [StorageData::save], but returning right away, deferring
+    /// the write until the web browser is idle through ``requestIdleCallback``, or until the next
+    /// turn of the event loop where it isn't supported, which avoids janking interactions that
+    /// change the value often, such as dragging.
+    ///
+    /// The value is serialized right away, as it might change before the write happens, so only
+    /// the write is deferred, being the pending write replaced by later calls, written right away
+    /// by [StorageData::save] or once this glue is dropped, and discarded by [StorageData::set]
+    /// and [StorageData::remove], as these replace the stored value.
+    ///
+    /// Only values that were mutated are saved, and this fails if the value could not be
+    /// serialized, returning an explanation to this through a [StorageError], while failures
+    /// writing the value are only logged, as they happen later.
+    pub fn save_when_idle(&mut self) -> Result<(), StorageError> {
+        if !self.mutated || self.value.get().is_none() {
+            return Ok(());
+        }
+        if self.idle_save.is_none() {
+            let idle_save = DebouncedSave::when_idle().map_err(StorageError::StorageUnavailable)?;
+            self.idle_save = Some(idle_save);
+        }
+        let write_is_pending = self.deferred_saves().any(DebouncedSave::is_pending);
+        if !write_is_pending {
+            self.rotate_history().map_err(StorageError::StorageUnavailable)?;
+        }
+        if let Some(debounced_save) = &self.debounced_save {
+            debounced_save.cancel();
+        }
+        let key = self.key.as_ref();
+        let serialized = self
+            .serialize(self.resolve())
+            .log_possible_error(|error| {
+                format!("Could not serialize item for key {key} due to:\n{error:?}")
+            })
+            .map_err(StorageError::SerdeSerialize)?;
+        if let Some(idle_save) = &self.idle_save {
+            idle_save
+                .schedule(self.item_store(), key, serialized)
+                .map_err(StorageError::StorageUnavailable)?;
+        }
+        self.mutated = false;
+        self.touch_mtime();
+        if let Some(on_change) = &self.on_change {
+            on_change(self.resolve());
+        }
+        Ok(())
+    }
+
+    /// Writes deferred by [StorageData::debounce_save] and [StorageData::save_when_idle].
+    fn deferred_saves(&self) -> impl Iterator<Item = &DebouncedSave> {
+        self.debounced_save.iter().chain(&self.idle_save)
+    }
+
     /// Finalization means updating the value if necessary and queried, and clear it if queried.
     fn finalize_use(&mut self, clear: bool, save: bool) {
         if save && self.save_on_drop {
@@ -500,10 +2485,23 @@ where
     }
 }
 
+/// View of a [StorageData] whose equality and hash are those of its key, as given by
+/// [StorageData::by_key], keeping key-based semantics apart from value-based ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ByKey<'key>(&'key str);
+
+impl<'key> ByKey<'key> {
+    /// Key of the glue this view was taken from.
+    pub const fn key(&self) -> &'key str {
+        self.0
+    }
+}
+
 /// Dereferences to the Storage's current glue data through a call to [StorageData::get].
-impl<Key, Value> Deref for StorageData<Key, Value>
+impl<Key, Value, Backend> Deref for StorageData<Key, Value, Backend>
 where
     Key: AsRef<str>,
+    Backend: StorageBackend,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
 {
     type Target = Value;
@@ -517,9 +2515,10 @@ where
 ///
 /// Calling this means the value will probably mutate, so the value gets marked as mutated
 /// once this is called, even if the value doesn't mutate in the end.
-impl<Key, Value> DerefMut for StorageData<Key, Value>
+impl<Key, Value, Backend> DerefMut for StorageData<Key, Value, Backend>
 where
     Key: AsRef<str>,
+    Backend: StorageBackend,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
 {
     /// Dereferences to the Storage's current glue data through a call to [StorageData::get_mut].
@@ -534,22 +2533,33 @@ where
 
 /// Upon drop, this value is tried to be saved, only if [StorageData::save_on_drop]
 /// isn't manually set as false.
-impl<Key, Value> Drop for StorageData<Key, Value>
+impl<Key, Value, Backend> Drop for StorageData<Key, Value, Backend>
 where
     Key: AsRef<str>,
+    Backend: StorageBackend,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
 {
     /// Upon drop, this value is tried to be saved, only if [StorageData::save_on_drop]
-    /// isn't manually set as false.
+    /// isn't manually set as false, and when the ``write_queue`` feature is enabled, every write
+    /// waiting in the queue is flushed, see [flush_pending], while every stream got through
+    /// [StorageData::watch] ends.
     fn drop(&mut self) {
         self.finalize_use(true, true);
+        #[cfg(feature = "write_queue")]
+        let _ = write_queue::flush_pending();
+        self.unregister_key();
+        #[cfg(feature = "watch")]
+        for (channel, _) in &self.watchers {
+            watch::WatchChannel::close(channel);
+        }
     }
 }
 
 /// Displays this glue value by getting it through [StorageData::get].
-impl<Key, Value> Display for StorageData<Key, Value>
+impl<Key, Value, Backend> Display for StorageData<Key, Value, Backend>
 where
     Key: AsRef<str>,
+    Backend: StorageBackend,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + Display,
 {
     /// Displays this glue value by getting it through [StorageData::get].
@@ -558,10 +2568,47 @@ where
     }
 }
 
+/// Serializes this glue transparently as its value, got through [StorageData::get].
+impl<Key, Value, Backend> serde::Serialize for StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Serializes this glue transparently as its value, got through [StorageData::get].
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+/// Deserializes a glue transparently from its value, yielding an unbound glue already holding
+/// said value, whose key is the default one and whose default value is the one given by
+/// [Default].
+///
+/// The glue must be bound to a key through [StorageData::with_key] before being persisted, as
+/// nothing is saved until then, not even on drop, and once bound, its value is marked as mutated,
+/// just like [StorageData::from_value], so the first save or drop persists it.
+impl<'de, Key, Value, Backend> serde::Deserialize<'de> for StorageData<Key, Value, Backend>
+where
+    Key: AsRef<str> + Default,
+    Backend: StorageBackend,
+    Value: serde::Serialize + for<'any> serde::de::Deserialize<'any> + Default,
+{
+    /// Deserializes a glue transparently from its value, yielding an unbound glue already holding
+    /// said value, which must be bound to a key through [StorageData::with_key].
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut data = Self::with_backend(Key::default(), Value::default);
+        data.value = OnceCell::with_value(Value::deserialize(deserializer)?);
+        data.unbound = true;
+        Ok(data)
+    }
+}
+
 /// Formats as debug using this glue value by getting it through [StorageData::get].
-impl<Key, Value> Debug for StorageData<Key, Value>
+impl<Key, Value, Backend> Debug for StorageData<Key, Value, Backend>
 where
     Key: AsRef<str>,
+    Backend: StorageBackend,
     Value: serde::Serialize + for<'de> serde::de::Deserialize<'de> + Debug,
 {
     /// Formats as debug using this glue value by getting it through [StorageData::get].
@@ -0,0 +1,185 @@
+use crate::log_error::LogError;
+use crate::serdes::Codec;
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::Rc;
+use alloc::string::String;
+use core::cell::{Cell, RefCell};
+use core::error::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Listener to the changes made to a key of a Storage from other tabs or windows, which the web
+/// browser notifies through ``storage`` events.
+///
+/// The listener is removed once this is dropped, so it must be kept alive as long as the changes
+/// are meant to be listened to.
+pub struct StorageListener {
+    window: web_sys::Window,
+    listener: Closure<dyn FnMut(web_sys::StorageEvent)>,
+    pending_timeout: Rc<Cell<Option<i32>>>,
+}
+
+impl StorageListener {
+    /// Listens to the changes made to the key of the storage, invoking ``on_change`` with the
+    /// change ``read_change`` reads from every ``storage`` event, such as its new serialized
+    /// value, see [new_value].
+    ///
+    /// When ``debounce_millis`` is set, changes are coalesced, invoking ``on_change`` once no
+    /// change has been made for said milliseconds, and only with the latest change.
+    pub(crate) fn new<Change, ReadChange, OnChange>(
+        storage: web_sys::Storage,
+        key: &str,
+        debounce_millis: Option<i32>,
+        read_change: ReadChange,
+        on_change: OnChange,
+    ) -> Result<Self, Box<dyn Error>>
+    where
+        Change: 'static,
+        ReadChange: Fn(&web_sys::StorageEvent) -> Change + 'static,
+        OnChange: FnMut(Change) + 'static,
+    {
+        let window = crate::window().map_log_possible_error(|_| "Could not get windows")?;
+        let key = String::from(key);
+        let pending_timeout = Rc::new(Cell::new(None));
+        let mut on_storage_change: Box<dyn FnMut(Change)> = match debounce_millis {
+            None => Box::new(on_change),
+            Some(debounce_millis) => {
+                let latest_value = Rc::new(RefCell::new(None));
+                let on_change = RefCell::new(on_change);
+                let on_timeout = {
+                    let latest_value = latest_value.clone();
+                    let pending_timeout = pending_timeout.clone();
+                    Closure::<dyn FnMut()>::new(move || {
+                        pending_timeout.set(None);
+                        if let Some(change) = latest_value.take() {
+                            (on_change.borrow_mut())(change);
+                        }
+                    })
+                };
+                let window = window.clone();
+                let pending_timeout = pending_timeout.clone();
+                Box::new(move |change| {
+                    latest_value.replace(Some(change));
+                    if let Some(timeout) = pending_timeout.take() {
+                        window.clear_timeout_with_handle(timeout);
+                    }
+                    let timeout = window
+                        .set_timeout_with_callback_and_timeout_and_arguments_0(
+                            on_timeout.as_ref().unchecked_ref(),
+                            debounce_millis,
+                        )
+                        .log_possible_error(|err| format!("Could not set timeout due to {err:?}"));
+                    pending_timeout.set(timeout.ok());
+                })
+            }
+        };
+        let listener = Closure::<dyn FnMut(web_sys::StorageEvent)>::new(
+            move |event: web_sys::StorageEvent| {
+                let is_this_storage = event.storage_area().as_ref() == Some(&storage);
+                let is_this_key = event.key().is_none_or(|event_key| event_key == key);
+                if is_this_storage && is_this_key {
+                    on_storage_change(read_change(&event));
+                }
+            },
+        );
+        window
+            .add_event_listener_with_callback("storage", listener.as_ref().unchecked_ref())
+            .map_log_possible_error(|err| {
+                format!("Could not listen to storage events due to {err:?}")
+            })?;
+        Ok(Self {
+            window,
+            listener,
+            pending_timeout,
+        })
+    }
+}
+
+/// Upon drop, the listener is removed, and any pending invocation is cancelled.
+impl Drop for StorageListener {
+    /// Upon drop, the listener is removed, and any pending invocation is cancelled.
+    fn drop(&mut self) {
+        let _ = self
+            .window
+            .remove_event_listener_with_callback("storage", self.listener.as_ref().unchecked_ref());
+        if let Some(timeout) = self.pending_timeout.take() {
+            self.window.clear_timeout_with_handle(timeout);
+        }
+    }
+}
+
+/// Reads the new serialized value of a ``storage`` event, being this ``None`` if the key was
+/// removed.
+pub(crate) fn new_value(event: &web_sys::StorageEvent) -> Option<String> {
+    event.new_value()
+}
+
+/// Change made to the key of a glue from another tab or window, as told by the ``storage`` event
+/// the web browser notifies, see [crate::StorageData::on_external_change_detailed].
+pub struct ExternalChange<Value> {
+    /// Key that changed, being ``None`` if the whole Storage was cleared.
+    pub key: Option<String>,
+    /// Serialized value the key had before the change, being ``None`` if it wasn't present.
+    pub old_value: Option<String>,
+    /// Serialized value the key has after the change, being ``None`` if it was removed.
+    pub new_value: Option<String>,
+    /// URL of the document whose script made the change.
+    pub url: String,
+    codec: Codec<Value>,
+    default_value: fn() -> Value,
+}
+
+impl<Value> ExternalChange<Value> {
+    /// Reads the change the ``storage`` event tells, keeping what is needed to deserialize the
+    /// new value later on.
+    pub(crate) fn new(
+        event: &web_sys::StorageEvent,
+        codec: Codec<Value>,
+        default_value: fn() -> Value,
+    ) -> Self {
+        Self {
+            key: event.key(),
+            old_value: event.old_value(),
+            new_value: event.new_value(),
+            url: event.url().unwrap_or_default(),
+            codec,
+            default_value,
+        }
+    }
+
+    /// Deserializes the new value, being the default value if the key was removed.
+    ///
+    /// This fails if the new value could not be deserialized, returning an explanation to this
+    /// through an ``Err<Box<dyn Error>>``.
+    pub fn value(&self) -> Result<Value, Box<dyn Error>>
+    where
+        Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    {
+        match &self.new_value {
+            None => Ok((self.default_value)()),
+            Some(serialized) => Ok(self.codec.deserialize(serialized.clone())?.0),
+        }
+    }
+}
+
+/// Turns a callback receiving values into one receiving the serialized values of the Storage,
+/// where removed keys are turned into the default value, and values that could not be
+/// deserialized are ignored.
+pub(crate) fn deserialize_changes<Value, Callback>(
+    codec: Codec<Value>,
+    default_value: fn() -> Value,
+    callback: Callback,
+) -> impl FnMut(Option<String>)
+where
+    Callback: Fn(Value),
+{
+    move |serialized| match serialized {
+        None => callback(default_value()),
+        Some(serialized) => {
+            if let Ok((value, _)) = codec.deserialize(serialized) {
+                callback(value)
+            }
+        }
+    }
+}
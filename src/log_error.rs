@@ -2,18 +2,76 @@ use alloc::boxed::Box;
 use alloc::format;
 use alloc::string::ToString;
 use core::error::Error;
+use core::sync::atomic::{AtomicPtr, AtomicU8, Ordering};
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::prelude::wasm_bindgen;
+#[cfg(target_arch = "wasm32")]
 use wasm_bindgen::JsValue;
 
+#[cfg(target_arch = "wasm32")]
 #[wasm_bindgen]
 extern "C" {
     #[wasm_bindgen(js_namespace = console, variadic)]
     pub fn error(items: Box<[JsValue]>);
 }
 
+/// How verbose the errors this crate logs to the console are.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LogLevel {
+    /// Nothing is logged, which is useful on production builds where failures are expected, such
+    /// as when the Storage is blocked and values fall back to their defaults.
+    Off = 0,
+    /// Errors are logged, including where they happened on debug builds, this is the default.
+    Error = 1,
+    /// Errors are logged including where they happened, even on release builds.
+    Debug = 2,
+}
+
+static LOG_LEVEL: AtomicU8 = AtomicU8::new(LogLevel::Error as u8);
+
+/// Sets how verbose the errors this crate logs to the console are, see [LogLevel].
+pub fn set_log_level(log_level: LogLevel) {
+    LOG_LEVEL.store(log_level as u8, Ordering::Relaxed);
+}
+
+/// Gets how verbose the errors this crate logs to the console are, see [LogLevel].
+pub fn log_level() -> LogLevel {
+    match LOG_LEVEL.load(Ordering::Relaxed) {
+        0 => LogLevel::Off,
+        1 => LogLevel::Error,
+        _ => LogLevel::Debug,
+    }
+}
+
+/// Function receiving the errors this crate logs, being null while they're logged through
+/// ``console.error``.
+static LOG_SINK: AtomicPtr<()> = AtomicPtr::new(core::ptr::null_mut());
+
+/// Routes the errors this crate logs to the indicated function rather than to ``console.error``,
+/// allowing to send them to a logging framework or to collect them for telemetry.
+///
+/// The verbosity set through [set_log_level] still applies to the errors given to the function.
+pub fn set_log_sink(sink: fn(&str)) {
+    LOG_SINK.store(sink as *mut (), Ordering::Relaxed);
+}
+
+fn log_sink() -> Option<fn(&str)> {
+    let sink = LOG_SINK.load(Ordering::Relaxed);
+    if sink.is_null() {
+        return None;
+    }
+    // SAFETY: LOG_SINK is only ever set from a fn(&str) on set_log_sink.
+    Some(unsafe { core::mem::transmute::<*mut (), fn(&str)>(sink) })
+}
+
 #[inline]
 pub fn log_error(message: &str) {
-    if cfg!(debug_assertions) {
+    let log_level = log_level();
+    if log_level == LogLevel::Off {
+        return;
+    }
+    if cfg!(debug_assertions) || log_level == LogLevel::Debug {
         let loc = core::panic::Location::caller();
         let msg = format!(
             "{} ({}:{}:{})",
@@ -22,9 +80,19 @@ pub fn log_error(message: &str) {
             loc.line(),
             loc.column()
         );
-        error(Box::from([JsValue::from(&msg)]));
+        write_log(&msg);
     } else {
-        error(Box::from([JsValue::from(message)]));
+        write_log(message);
+    }
+}
+
+fn write_log(message: &str) {
+    match log_sink() {
+        Some(sink) => sink(message),
+        #[cfg(target_arch = "wasm32")]
+        None => error(Box::from([JsValue::from(message)])),
+        #[cfg(not(target_arch = "wasm32"))]
+        None => std::eprintln!("{message}"),
     }
 }
 
@@ -60,10 +128,7 @@ impl<T> LogError for Option<T> {
         Description: AsRef<str>,
         DescriptionGetter: FnOnce(&Self::ErrorType) -> Description,
     {
-        match &self {
-            None => log_error(error_descriptor(&()).as_ref()),
-            Some(_) => {}
-        };
+        if self.is_none() { log_error(error_descriptor(&()).as_ref()) };
         self
     }
 
@@ -98,10 +163,7 @@ impl<T, E> LogError for Result<T, E> {
         Description: AsRef<str>,
         DescriptionGetter: FnOnce(&Self::ErrorType) -> Description,
     {
-        match &self {
-            Err(err) => log_error(&error_descriptor(err).as_ref()),
-            Ok(_) => {}
-        };
+        if let Err(err) = &self { log_error(error_descriptor(err).as_ref()) };
         self
     }
 
@@ -116,7 +178,7 @@ impl<T, E> LogError for Result<T, E> {
         match self {
             Err(err) => {
                 let error = error_descriptor(&err).as_ref().to_string();
-                log_error(&*error);
+                log_error(&error);
                 Err(error.into())
             }
             Ok(v) => Ok(v),
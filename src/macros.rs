@@ -9,13 +9,33 @@ use crate::StorageData;
 ///
 /// *vis:vis* *struct:ident* with storage data { <br>
 /// &nbsp;&nbsp;       len: *len:literal*, <br>
+/// &nbsp;&nbsp;       $(fields: *fields:ident*,)? <br>
+/// &nbsp;&nbsp;       $(snapshot: *snapshot:ident*,)? <br>
+/// &nbsp;&nbsp;       $(builder: *builder:ident*,)? <br>
+/// &nbsp;&nbsp;       $(partial eq: *partial_eq:tt*,)? <br>
+/// &nbsp;&nbsp;       $(merge: *merge:tt*,)? <br>
+/// &nbsp;&nbsp;       $(generics: [*generic:ident*,*] where [*where:tt**],)? <br>
 /// &nbsp;&nbsp;       constructor visibility: *constructor_visibility:vis*, <br>
+/// &nbsp;&nbsp;       $(field visibility: *field_visibility:vis*,)? <br>
+/// &nbsp;&nbsp;       $(flattened: { $( <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           *flatten_variable:ident* : *flatten_type:ty*,
+/// named *flatten_prefix:literal*, with documentation *flatten_doc:literal*; <br>
+/// &nbsp;&nbsp;       )* },)? <br>
 /// &nbsp;&nbsp;       $({ <br>
-/// &nbsp;&nbsp;&nbsp;&nbsp;           variable *storage_variable_name:ident*, <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           variable *storage_variable_name:tt*, <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           $(with accessor *storage_accessor:ident*,)? <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           $(with getters *storage_getter:ident*,
+/// *storage_getter_mut:ident*,)? <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           $(with reset *storage_reset:ident*,)? <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           $(with builder *storage_with_storage:ident*,
+/// *storage_with_default:ident*,)? <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           $(with optional *storage_or:ident*, *storage_is_present:ident*,
+/// *storage_optional_type:ty*,)? <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;           type *storage_type:ty*, <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;           named *storage_web_name:literal*, <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;           default {*storage_default:expr*}, <br>
-/// &nbsp;&nbsp;&nbsp;&nbsp;           with storage kind *storage_kind:path*, <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           with storage kind *storage_kind:expr*, <br>
+/// &nbsp;&nbsp;&nbsp;&nbsp;           with ttl ms *storage_ttl_ms:expr*, <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;           with documentation *storage_doc:literal*, <br>
 /// &nbsp;&nbsp;&nbsp;&nbsp;           storage kind for doc *storage_kind_for_doc:literal*, <br>
 /// &nbsp;&nbsp;       })*<br>
@@ -26,57 +46,550 @@ use crate::StorageData;
 /// - vis: The visibility of the struct and that of most of the functions.
 /// - struct: The name of the struct to generate that will hold all the glues.
 /// - len: Amount of glues inside the struct.
-/// - constructor_visibility: Visibility of the ***new*** function.
+/// - fields: Optional kind of fields of the struct, being this either ``named`` for a struct with
+///   named fields, which is the default, or ``unnamed`` for a tuple struct.
+/// - snapshot: Optional name of a struct to generate holding owned copies of every glue's value,
+///   along with a ***snapshot*** function creating it, this struct derives [Clone], [serde::Serialize]
+///   and [serde::Deserialize], so every glue's type must implement [Clone].
+/// - builder: Optional name of a struct to generate along with a ***builder*** function creating
+///   it, which allows overriding the storage kind and default value of every glue before building
+///   the struct, every glue must then be given the names of its builder methods.
+/// - partial_eq: Optional boolean telling whether to implement [PartialEq] for the struct,
+///   comparing the value of every glue, which requires resolving them, reading them from the
+///   Storage if they weren't read yet, so every glue's type must implement [PartialEq].
+/// - merge: Optional boolean telling whether to generate the ***merge_from*** and
+///   ***merge_changed_only*** functions, copying the value of every glue of another instance,
+///   such as a preset, and those of flattened structs, which must generate them too, so every
+///   glue's type must implement [Clone] and [PartialEq].
+/// - generic: Optional type parameters of the struct, every one of them is required to implement
+///   [serde::Serialize] and [serde::Deserialize].
+/// - where: Further bounds of the type parameters, written as the predicates of a where clause,
+///   each of them followed by a comma.
+/// - constructor_visibility: Visibility of the ***new*** and ***new_with_prefix*** functions.
+/// - field_visibility: Optional visibility of the glues, being *vis* by default, where
+///   ``pub(self)`` makes them private, so they are only reachable through their getters.
+/// - For every flattened struct, only supported on structs with named fields:
+///   - flatten_variable: Name of the variable that will hold the struct.
+///   - flatten_type: Type of the struct, which must be generated by this macro too.
+///   - flatten_prefix: Prefix of the keys of every glue of the flattened struct, which is created
+///     through its ***new_with_prefix*** with this prefix preceded by the one given to this
+///     struct's ***new_with_prefix***, if any.
+///   - flatten_doc: Documentation of the variable.
 /// - For every glue to create:
-///   - storage_variable_name: Name of the variable that will hold the glue.
+///   - storage_variable_name: Name of the variable that will hold the glue, or its index if the
+///     struct is a tuple struct.
+///   - storage_accessor: Optional name of a method returning a mutable reference to the glue.
+///   - storage_getter, storage_getter_mut: Optional names of methods returning a reference and a
+///     mutable reference to the glue, meant for private glues.
+///   - storage_reset: Optional name of a method removing the glue's value from the Storage, so
+///     its next read returns the default value.
+///   - storage_with_storage, storage_with_default: Names of the methods of the builder overriding
+///     the storage kind and the default value of the glue, required if the struct has a builder.
+///   - storage_or, storage_is_present, storage_optional_type: Optional, for glues whose type is
+///     an [Option] of *storage_optional_type*, names of a method getting the contained value or a
+///     fallback, and of a method telling whether the glue contains a value.
 ///   - storage_type: Type of the variable this glue stores.
 ///   - storage_web_name: Key used in the Web Storage, since it is Web Storage,
-/// the conventions don't need to match Rust's.
+///     the conventions don't need to match Rust's, when the struct is created through
+///     ***new_with_prefix***, the key is preceded by the prefix given to it.
 ///   - storage_default: Default value to get when value isn't present in the Storage.
-///   - storage_kind: Storage Kind to use, being this either Local or Session.
-///   - storage_doc: Documentation of the variable.
+///   - storage_kind: Storage Kind to use, being this either Local, Session or Cookie.
+///   - storage_ttl_ms: Optional milliseconds after which the stored value expires.
+///   - storage_doc: Documentation of the variable, also given at runtime by ***describe***.
 ///   - storage_kind_for_doc: The name of the Storage Kind used for this Glue, this
-/// value is used to tell the name of the storage type in the documentation.
+///     value is used to tell the name of the storage type in the documentation.
+///
+/// When the ``use_serde_json`` feature is enabled, the struct also gets ***export_json***,
+/// ***import_json*** and ***import_json_strict*** functions, exporting and importing every glue's
+/// value as a single JSON document mapping every web name to its value, along with a [TryFrom]
+/// implementation creating the struct from such a document, failing with a [crate::StorageError].
+///
+/// Flattened structs are saved, cleared, reset and reloaded along with the glues of this struct,
+/// and taken into account when telling whether it's dirty, while the rest of functions, such as
+/// ***len***, ***web_keys*** and ***KEYS***, only concern the glues of this struct, and as
+/// flattened structs are created through their ***new_with_prefix***, ***new*** can't be a const
+/// function.
+///
+/// Besides the inherent functions, [Default] is implemented by calling ***new***, so the struct
+/// must not derive nor implement [Default] by itself.
 #[macro_export]
 macro_rules! define_storage {
+    (@struct_vis [] $vis:vis, $($tokens:tt)*) => {
+        $crate::define_storage!{ @struct $vis, $($tokens)* }
+    };
+    (@struct_vis [$field_vis:vis] $vis:vis, $($tokens:tt)*) => {
+        $crate::define_storage!{ @struct $field_vis, $($tokens)* }
+    };
+    (@struct $field_vis:vis, [$(named)?] $doc:expr, $vis:vis $struct:ident
+        [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt : $storage_type:ty, $storage_doc:literal;)*
+    } flattened {
+        $($flatten_variable:ident : $flatten_type:ty, $flatten_doc:literal;)*
+    }) => {
+        #[doc = $doc]
+        $vis struct $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            $(
+                #[doc = $storage_doc]
+                $field_vis $storage_variable_name : ::storage_data::StorageData<
+                    ::storage_data::__private::Cow<'static, str>,
+                    $storage_type,
+                >,
+            )*
+            $(
+                #[doc = $flatten_doc]
+                $field_vis $flatten_variable : $flatten_type,
+            )*
+        }
+    };
+    (@struct $field_vis:vis, [unnamed] $doc:expr, $vis:vis $struct:ident
+        [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt : $storage_type:ty, $storage_doc:literal;)*
+    } flattened {}) => {
+        #[doc = $doc]
+        $vis struct $struct<$($generic),*> (
+            $(
+                #[doc = $storage_doc]
+                $field_vis ::storage_data::StorageData<
+                    ::storage_data::__private::Cow<'static, str>,
+                    $storage_type,
+                >,
+            )*
+        )
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*;
+    };
+    (@snapshot [] [$($fields:ident)?] $vis:vis $struct:ident
+        [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt : $storage_type:ty, $storage_doc:literal;)*
+    }) => {};
+    (@snapshot [$snapshot:ident] [$(named)?] $vis:vis $struct:ident
+        [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt : $storage_type:ty, $storage_doc:literal;)*
+    }) => {
+        #[doc = "Owned copies of the values of every glue."]
+        #[derive(Clone, ::storage_data::serde::Serialize, ::storage_data::serde::Deserialize)]
+        #[serde(crate = "::storage_data::serde")]
+        $vis struct $snapshot<$($generic),*> where $($where)* {
+            $(
+                #[doc = $storage_doc]
+                $vis $storage_variable_name : $storage_type,
+            )*
+        }
+
+        $crate::define_storage!{
+            @snapshot fn $vis $struct, $snapshot [$($generic),*] [$($where)*] {
+                $($storage_variable_name)*
+            }
+        }
+    };
+    (@snapshot [$snapshot:ident] [unnamed] $vis:vis $struct:ident
+        [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt : $storage_type:ty, $storage_doc:literal;)*
+    }) => {
+        #[doc = "Owned copies of the values of every glue."]
+        #[derive(Clone, ::storage_data::serde::Serialize, ::storage_data::serde::Deserialize)]
+        #[serde(crate = "::storage_data::serde")]
+        $vis struct $snapshot<$($generic),*> (
+            $(
+                #[doc = $storage_doc]
+                $vis $storage_type,
+            )*
+        ) where $($where)*;
+
+        $crate::define_storage!{
+            @snapshot fn $vis $struct, $snapshot [$($generic),*] [$($where)*] {
+                $($storage_variable_name)*
+            }
+        }
+    };
+    (@snapshot fn $vis:vis $struct:ident, $snapshot:ident
+        [$($generic:ident),*] [$($where:tt)*] { $($storage_variable_name:tt)* }) => {
+        impl<$($generic),*> $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>
+                + ::core::clone::Clone,)*
+            $($where)*
+        {
+            #[doc = concat!("Resolves every glue and copies its value into a [",
+                stringify!($snapshot), "].")]
+            $vis fn snapshot(&self) -> $snapshot<$($generic),*> {
+                $snapshot {
+                    $(
+                        $storage_variable_name : ::core::clone::Clone::clone(
+                            self.$storage_variable_name.get()
+                        ),
+                    )*
+                }
+            }
+        }
+    };
+    (@builder [] $($tokens:tt)*) => {};
+    (@builder [$builder:ident] $vis:vis $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        constructor visibility: $constructor_visibility:vis,
+        $({
+            variable $storage_variable_name:tt,
+            with builder $storage_with_storage:ident, $storage_with_default:ident,
+            type $storage_type:ty,
+            named $storage_web_name:literal,
+        })*
+    }) => {
+        #[doc = concat!("Builder of [", stringify!($struct), "], overriding the storage kind and \
+        default value of any glue before building it.")]
+        $vis struct $builder<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            storage: $struct<$($generic),*>,
+        }
+
+        impl<$($generic),*> $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            #[doc = concat!("Creates a [", stringify!($builder), "], allowing to override the \
+            storage kind and default value of any glue, such as to store them in the Session \
+            Storage when browsing in private, before building the ", stringify!($struct), ".")]
+            $constructor_visibility fn builder() -> $builder<$($generic),*> {
+                $builder { storage: Self::new() }
+            }
+        }
+
+        impl<$($generic),*> $builder<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            $(
+                #[doc = concat!("Stores the glue under the key ", $storage_web_name,
+                " in the indicated storage kind.")]
+                $vis fn $storage_with_storage(
+                    mut self,
+                    storage_kind: ::storage_data::StorageKind,
+                ) -> Self {
+                    self.storage.$storage_variable_name =
+                        self.storage.$storage_variable_name.with_storage(storage_kind);
+                    self
+                }
+                #[doc = concat!("Changes the function giving the default value of the glue under \
+                the key ", $storage_web_name, ".")]
+                $vis fn $storage_with_default(mut self, default: fn() -> $storage_type) -> Self {
+                    self.storage.$storage_variable_name.set_default(default);
+                    self
+                }
+            )*
+            #[doc = concat!("Builds the ", stringify!($struct), ".")]
+            $vis fn build(self) -> $struct<$($generic),*> {
+                self.storage
+            }
+        }
+    };
+    (@new [] [$constructor_visibility:vis] { $($body:tt)* }) => {
+        #[doc = "Creates a new instance where every glue is uninitialized."]
+        $constructor_visibility const fn new() -> Self {
+            $($body)*
+        }
+    };
+    (@new [$($flatten_variable:ident)+] [$constructor_visibility:vis] { $($body:tt)* }) => {
+        #[doc = "Creates a new instance where every glue is uninitialized, including those of"]
+        #[doc = "every flattened struct."]
+        $constructor_visibility fn new() -> Self {
+            Self::new_with_prefix("")
+        }
+    };
+    (@partial_eq [$(false)?] $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt)*
+    }) => {};
+    (@partial_eq [true] $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt)*
+    }) => {
+        impl<$($generic),*> ::core::cmp::PartialEq for $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>
+                + ::core::cmp::PartialEq,)*
+            $($where)*
+        {
+            fn eq(&self, other: &Self) -> bool {
+                true $(&& self.$storage_variable_name.get() == other.$storage_variable_name.get())*
+            }
+        }
+    };
+    (@merge [$(false)?] $vis:vis $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        $($tokens:tt)*
+    }) => {};
+    (@merge [true] $vis:vis $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        flattened { $($flatten_variable:ident)* }
+        $($storage_variable_name:tt)*
+    }) => {
+        impl<$($generic),*> $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>
+                + ::core::clone::Clone
+                + ::core::cmp::PartialEq,)*
+            $($where)*
+        {
+            #[doc = "Copies the value of every glue of the other instance into this one, such as"]
+            #[doc = "when applying a preset, marking them as mutated, so they are saved on the"]
+            #[doc = "next save or once this is dropped."]
+            $vis fn merge_from(&mut self, other: &Self) {
+                $(
+                    *self.$storage_variable_name = other.$storage_variable_name.get().clone();
+                )*
+                $(
+                    self.$flatten_variable.merge_from(&other.$flatten_variable);
+                )*
+            }
+            #[doc = "Copies the value of every glue of the other instance into this one just like"]
+            #[doc = "***merge_from***, but only those whose value differs from their default"]
+            #[doc = "value, so a preset only overrides the values it actually sets."]
+            $vis fn merge_changed_only(&mut self, other: &Self) {
+                $(
+                    let value = other.$storage_variable_name.get();
+                    if *value != other.$storage_variable_name.default() {
+                        *self.$storage_variable_name = value.clone();
+                    }
+                )*
+                $(
+                    self.$flatten_variable.merge_changed_only(&other.$flatten_variable);
+                )*
+            }
+        }
+    };
     ($vis:vis $struct:ident with storage data {
         len: $len:literal,
+        $(fields: $fields:ident,)?
+        $(snapshot: $snapshot:ident,)?
+        $(builder: $builder:ident,)?
+        $(partial eq: $partial_eq:tt,)?
+        $(merge: $merge:tt,)?
+        $(generics: [$($generic:ident),*] where [$($where:tt)*],)?
         constructor visibility: $constructor_visibility:vis,
+        $(field visibility: $field_vis:vis,)?
+        $(flattened: {$(
+            $flatten_variable:ident : $flatten_type:ty, named $flatten_prefix:literal,
+            with documentation $flatten_doc:literal;
+        )*},)?
         $({
-            variable $storage_variable_name:ident,
+            variable $storage_variable_name:tt,
+            $(with accessor $storage_accessor:ident,)?
+            $(with getters $storage_getter:ident, $storage_getter_mut:ident,)?
+            $(with reset $storage_reset:ident,)?
+            $(with builder $storage_with_storage:ident, $storage_with_default:ident,)?
+            $(with optional $storage_or:ident, $storage_is_present:ident,
+                $storage_optional_type:ty,)?
             type $storage_type:ty,
             named $storage_web_name:literal,
             default {$storage_default:expr},
-            $(with storage kind $storage_kind:path,)?
+            $(with storage kind $storage_kind:expr,)?
+            $(with ttl ms $storage_ttl_ms:expr,)?
             with documentation $storage_doc:literal,
             storage kind for doc $storage_kind_for_doc:literal,
         })*
     } ) => {
-        #[doc = concat!("Glues to Local/Session storages:",
-        $( "\n - ", stringify!($storage_variable_name), " in ", $storage_kind_for_doc,
-        " Storage: ", $storage_doc, )*
-        )]
-        $vis struct $struct {
-            $(
-                #[doc = $storage_doc]
-                $vis $storage_variable_name : ::storage_data
-                    ::StorageData<&'static str, $storage_type>,
-            )*
+        $crate::define_storage!{
+            @struct_vis [$($field_vis)?] $vis, [$($fields)?]
+            concat!("Glues to Local/Session storages:",
+            $( "\n - ", stringify!($storage_variable_name), " in ", $storage_kind_for_doc,
+            " Storage: ", $storage_doc, )*
+            ), $vis $struct [$($($generic),*)?] [$($($where)*)?] {
+                $($storage_variable_name : $storage_type, $storage_doc;)*
+            } flattened {
+                $($($flatten_variable : $flatten_type, $flatten_doc;)*)?
+            }
+        }
+
+        $crate::__define_storage_json!{
+            $vis $struct [$($($generic),*)?] [$($($where)*)?] {
+                $($storage_variable_name, $storage_web_name, $storage_type;)*
+            }
         }
 
-        impl $struct{
-            #[doc = "Creates a new instance where every glue is uninitialized."]
-            $constructor_visibility const fn new() -> Self {
+        $crate::define_storage!{
+            @snapshot [$($snapshot)?] [$($fields)?] $vis $struct
+                [$($($generic),*)?] [$($($where)*)?] {
+                $($storage_variable_name : $storage_type, $storage_doc;)*
+            }
+        }
+
+        $crate::define_storage!{
+            @builder [$($builder)?] $vis $struct [$($($generic),*)?] [$($($where)*)?] {
+                constructor visibility: $constructor_visibility,
+                $({
+                    variable $storage_variable_name,
+                    $(with builder $storage_with_storage, $storage_with_default,)?
+                    type $storage_type,
+                    named $storage_web_name,
+                })*
+            }
+        }
+
+        $crate::define_storage!{
+            @partial_eq [$($partial_eq)?] $struct [$($($generic),*)?] [$($($where)*)?] {
+                $($storage_variable_name)*
+            }
+        }
+
+        $crate::define_storage!{
+            @merge [$($merge)?] $vis $struct [$($($generic),*)?] [$($($where)*)?] {
+                flattened { $($($flatten_variable)*)? }
+                $($storage_variable_name)*
+            }
+        }
+
+        $crate::define_storage!{
+            @impl $vis $struct [$($($generic),*)?] [$($($where)*)?] {
+                len: $len,
+                constructor visibility: $constructor_visibility,
+                flattened {
+                    $($($flatten_variable : $flatten_type, $flatten_prefix;)*)?
+                }
+                $({
+                    variable $storage_variable_name,
+                    $(with accessor $storage_accessor,)?
+                    $(with getters $storage_getter, $storage_getter_mut,)?
+                    $(with reset $storage_reset,)?
+                    $(with optional $storage_or, $storage_is_present, $storage_optional_type,)?
+                    type $storage_type,
+                    named $storage_web_name,
+                    default {$storage_default},
+                    $(with storage kind $storage_kind,)?
+                    $(with ttl ms $storage_ttl_ms,)?
+                    with documentation $storage_doc,
+                })*
+            }
+        }
+    };
+    (@impl $vis:vis $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        len: $len:literal,
+        constructor visibility: $constructor_visibility:vis,
+        flattened {
+            $($flatten_variable:ident : $flatten_type:ty, $flatten_prefix:literal;)*
+        }
+        $({
+            variable $storage_variable_name:tt,
+            $(with accessor $storage_accessor:ident,)?
+            $(with getters $storage_getter:ident, $storage_getter_mut:ident,)?
+            $(with reset $storage_reset:ident,)?
+            $(with optional $storage_or:ident, $storage_is_present:ident,
+                $storage_optional_type:ty,)?
+            type $storage_type:ty,
+            named $storage_web_name:literal,
+            default {$storage_default:expr},
+            $(with storage kind $storage_kind:expr,)?
+            $(with ttl ms $storage_ttl_ms:expr,)?
+            with documentation $storage_doc:literal,
+        })*
+    }) => {
+        impl<$($generic),*> $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            $crate::define_storage!{
+                @new [$($flatten_variable)*] [$constructor_visibility] {
+                    Self {
+                        $(
+                            $storage_variable_name : ::storage_data::StorageData
+                                ::<::storage_data::__private::Cow<'static, str>, $storage_type>
+                                ::new(
+                                    ::storage_data::__private::Cow::Borrowed($storage_web_name),
+                                    || $storage_default,
+                                )
+                                $(.with_storage($storage_kind))?
+                                $(.with_ttl($storage_ttl_ms))?
+                                ,
+                        )*
+                    }
+                }
+            }
+            #[doc = "Creates a new instance where every glue is uninitialized, and whose keys"]
+            #[doc = "start by the indicated prefix, decided at runtime, such as the id of the"]
+            #[doc = "logged user, allowing the same struct to be stored under many namespaces."]
+            $constructor_visibility fn new_with_prefix(prefix: &str) -> Self {
                 Self {
                     $(
                         $storage_variable_name : ::storage_data::StorageData
-                            ::<&'static str, $storage_type>
-                            ::new($storage_web_name, || $storage_default)
+                            ::<::storage_data::__private::Cow<'static, str>, $storage_type>
+                            ::new(
+                                ::storage_data::__private::Cow::Owned(
+                                    [prefix, $storage_web_name].concat()
+                                ),
+                                || $storage_default,
+                            )
                             $(.with_storage($storage_kind))?
+                            $(.with_ttl($storage_ttl_ms))?
                             ,
                     )*
+                    $(
+                        $flatten_variable : <$flatten_type>::new_with_prefix(
+                            &[prefix, $flatten_prefix].concat()
+                        ),
+                    )*
                 }
             }
+            $($(
+                #[doc = concat!("Glue stored under the key ", $storage_web_name, ".")]
+                $vis fn $storage_accessor(&mut self) -> &mut ::storage_data::StorageData<
+                    ::storage_data::__private::Cow<'static, str>,
+                    $storage_type,
+                > {
+                    &mut self.$storage_variable_name
+                }
+            )?)*
+            $($(
+                #[doc = concat!("Glue stored under the key ", $storage_web_name, ".")]
+                $vis fn $storage_getter(&self) -> &::storage_data::StorageData<
+                    ::storage_data::__private::Cow<'static, str>,
+                    $storage_type,
+                > {
+                    &self.$storage_variable_name
+                }
+                #[doc = concat!("Glue stored under the key ", $storage_web_name,
+                ", allowing to change its value.")]
+                $vis fn $storage_getter_mut(&mut self) -> &mut ::storage_data::StorageData<
+                    ::storage_data::__private::Cow<'static, str>,
+                    $storage_type,
+                > {
+                    &mut self.$storage_variable_name
+                }
+            )?)*
+            $($(
+                #[doc = concat!("Removes the value stored under the key ", $storage_web_name,
+                ", so its next read returns the default value.")]
+                $vis fn $storage_reset(&mut self) -> Result<(), ::storage_data::StorageError> {
+                    self.$storage_variable_name.remove()
+                }
+            )?)*
+            $($(
+                #[doc = concat!("Gets the value stored under the key ", $storage_web_name,
+                ", or the indicated fallback if there is none.")]
+                $vis fn $storage_or<'fallback>(
+                    &'fallback self,
+                    fallback: &'fallback $storage_optional_type,
+                ) -> &'fallback $storage_optional_type {
+                    self.$storage_variable_name.get().as_ref().unwrap_or(fallback)
+                }
+                #[doc = concat!("Tells whether there is a value stored under the key ",
+                $storage_web_name, ".")]
+                $vis fn $storage_is_present(&self) -> bool {
+                    self.$storage_variable_name.get().is_some()
+                }
+            )?)*
             #[doc = "Amount of glues."]
             $vis const fn len(&self) -> usize {
                 $len
@@ -91,6 +604,72 @@ macro_rules! define_storage {
                 )*
                 len
             }
+            #[doc = "Web name of every glue, along with whether it is initialized and the"]
+            #[doc = "estimated amount of bytes the Storage takes to keep it, if it is stored,"]
+            #[doc = "which is useful to render a table on a settings inspector."]
+            $vis fn report(&self) -> Vec<(&'static str, bool, Option<usize>)> {
+                Vec::from([$(
+                    (
+                        $storage_web_name,
+                        self.$storage_variable_name.is_set(),
+                        self.$storage_variable_name.size_bytes().ok().flatten(),
+                    ),
+                )*])
+            }
+            #[doc = "Tells whether any glue was mutated and not saved yet."]
+            $vis fn is_dirty(&self) -> bool {
+                false $(|| self.$storage_variable_name.is_dirty())*
+                    $(|| self.$flatten_variable.is_dirty())*
+            }
+            #[doc = "Estimated amount of bytes the Storage takes to keep every glue, skipping"]
+            #[doc = "those that aren't stored or can't be read."]
+            $vis fn total_size_bytes(&self) -> usize {
+                0 $(+ self.$storage_variable_name.size_bytes().ok().flatten().unwrap_or(0))*
+            }
+            #[doc = "Web names of every glue, without the prefix given to ***new_with_prefix***,"]
+            #[doc = "known at compile time, so keys can be checked on const contexts, such as"]
+            #[doc = "asserting no two storage structs share a key."]
+            $vis const KEYS: [&'static str; $len] = [$($storage_web_name,)*];
+            #[doc = "Web names of every glue, without the prefix given to ***new_with_prefix***."]
+            $vis const fn web_keys(&self) -> [&'static str; $len] {
+                Self::KEYS
+            }
+            #[doc = "Web name of every glue along with its documentation, which is useful to"]
+            #[doc = "render help text next to each control on a settings page."]
+            $vis fn describe(&self) -> Vec<(&'static str, &'static str)> {
+                Vec::from([$(($storage_web_name, $storage_doc.trim()),)*])
+            }
+            #[doc = "Web names of every initialized glue."]
+            $vis fn initialized_keys(&self) -> Vec<&'static str> {
+                let mut initialized_keys = Vec::new();
+                $(
+                    if self.$storage_variable_name.is_set(){
+                        initialized_keys.push($storage_web_name);
+                    }
+                )*
+                initialized_keys
+            }
+            #[doc = "Discards the value of every glue, including any mutation that wasn't"]
+            #[doc = "saved, and retrieves them again from the Storage."]
+            $vis fn reload_all(&mut self) {
+                $(
+                    self.$storage_variable_name.reload();
+                )*
+                $(
+                    self.$flatten_variable.reload_all();
+                )*
+            }
+            #[doc = "Replaces the value of every glue whose stored value is corrupt with its"]
+            #[doc = "default value, and returns the web names of those that were repaired."]
+            $vis fn repair_all(&mut self) -> Vec<&'static str> {
+                let mut repaired_storages = Vec::new();
+                $(
+                    if let Ok(true) = self.$storage_variable_name.verify_and_repair() {
+                        repaired_storages.push($storage_web_name);
+                    }
+                )*
+                repaired_storages
+            }
             #[doc = "Destroys every glue's value, and returns the web names of those that"]
             #[doc = "failed to be deleted."]
             $vis fn clear(&mut self, list_failed_storages:bool) -> Result<(), Vec<&'static str>> {
@@ -104,12 +683,39 @@ macro_rules! define_storage {
                         }
                     }
                 )*
+                $(
+                    if let Err(failed_flattened) =
+                        self.$flatten_variable.clear(list_failed_storages) {
+                        error = true;
+                        failed_storages.extend(failed_flattened);
+                    }
+                )*
                 if error {
                     Err(failed_storages)
                 } else {
                     Ok(())
                 }
             }
+            #[doc = "Removes the value of every glue, so their next reads return their default"]
+            #[doc = "values, and returns the web names of those that failed to be removed."]
+            $vis fn reset_all(&mut self) -> Result<(), Vec<&'static str>> {
+                let mut failed_storages = Vec::new();
+                $(
+                    if self.$storage_variable_name.remove().is_err() {
+                        failed_storages.push($storage_web_name);
+                    }
+                )*
+                $(
+                    if let Err(failed_flattened) = self.$flatten_variable.reset_all() {
+                        failed_storages.extend(failed_flattened);
+                    }
+                )*
+                if failed_storages.is_empty() {
+                    Ok(())
+                } else {
+                    Err(failed_storages)
+                }
+            }
             #[doc = concat!("Saves the value of every glue, and returns the web names of those \
             that failed.\n\nWhen dropping the glues, all of the glues' values will be  \
             automatically saved if they were modified, meaning this function will be called in \
@@ -127,6 +733,13 @@ macro_rules! define_storage {
                         }
                     }
                 )*
+                $(
+                    if let Err(failed_flattened) =
+                        self.$flatten_variable.save(list_failed_storages) {
+                        error = true;
+                        failed_storages.extend(failed_flattened);
+                    }
+                )*
                 if error {
                     Err(failed_storages)
                 } else {
@@ -134,5 +747,117 @@ macro_rules! define_storage {
                 }
             }
         }
+
+        impl<$($generic),*> ::core::default::Default for $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
     };
+}
+
+/// Creates a [crate::StorageArray] of the indicated storage kind and amount of glues, whose keys
+/// are made of the indicated prefix followed by the index of the glue, and whose default value is
+/// the indicated expression, for example:
+///
+/// ```rust no_run
+/// use storage_data::{storage_array, StorageKind};
+///
+/// // Glues stored under the keys 'feature_flag_0' to 'feature_flag_31'.
+/// let mut feature_flags = storage_array!(StorageKind::Local, "feature_flag_", 32, false);
+/// feature_flags.set(3, true).unwrap();
+/// assert!(*feature_flags.get(3));
+/// ```
+#[macro_export]
+macro_rules! storage_array {
+    ($storage_kind:expr, $prefix:expr, $count:expr, $default:expr $(,)?) => {
+        $crate::StorageArray::<_, $count>::new($storage_kind, $prefix, || $default)
+    };
+}
+
+#[cfg(feature = "use_serde_json")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_storage_json {
+    ($vis:vis $struct:ident [$($generic:ident),*] [$($where:tt)*] {
+        $($storage_variable_name:tt, $storage_web_name:literal, $storage_type:ty;)*
+    }) => {
+        impl<$($generic),*> $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            #[doc = "Exports the value of every glue as a single JSON document mapping every web"]
+            #[doc = "name to its value, such as for an \"export settings\" button."]
+            $vis fn export_json(&self) -> Result<String, Box<dyn ::core::error::Error>> {
+                let mut json = ::storage_data::__private::JsonMap::new();
+                $(
+                    ::storage_data::__private::insert_json(
+                        &mut json,
+                        $storage_web_name,
+                        self.$storage_variable_name.get(),
+                    )?;
+                )*
+                ::storage_data::__private::json_to_string(&json)
+            }
+            #[doc = "Sets the value of every glue whose web name is present in the JSON document,"]
+            #[doc = "as exported by ***export_json***, ignoring unknown web names."]
+            $vis fn import_json(&mut self, json: &str) -> Result<(), Box<dyn ::core::error::Error>> {
+                let mut json = ::storage_data::__private::json_from_str(json)?;
+                $(
+                    if let Some(value) = ::storage_data::__private::take_json::<$storage_type>(
+                        &mut json,
+                        $storage_web_name,
+                    ) {
+                        self.$storage_variable_name.set(value?)?;
+                    }
+                )*
+                Ok(())
+            }
+            #[doc = "Sets the value of every glue whose web name is present in the JSON document"]
+            #[doc = "just like ***import_json***, but failing without setting any value if the"]
+            #[doc = "document contains unknown web names, listing them in the error."]
+            $vis fn import_json_strict(
+                &mut self,
+                json: &str,
+            ) -> Result<(), Box<dyn ::core::error::Error>> {
+                ::storage_data::__private::check_json_keys(
+                    &::storage_data::__private::json_from_str(json)?,
+                    &[$($storage_web_name,)*],
+                )?;
+                self.import_json(json)
+            }
+        }
+
+        #[doc = "Creates a new instance through ***new*** and sets the value of every glue whose"]
+        #[doc = "web name is present in the JSON document through ***import_json***, so the"]
+        #[doc = "values are also written to the Storage."]
+        impl<'json, $($generic),*> ::core::convert::TryFrom<&'json str> for $struct<$($generic),*>
+        where
+            $($generic: ::storage_data::serde::Serialize
+                + for<'de> ::storage_data::serde::Deserialize<'de>,)*
+            $($where)*
+        {
+            type Error = ::storage_data::StorageError;
+
+            fn try_from(json: &'json str) -> Result<Self, Self::Error> {
+                let mut storage = Self::new();
+                storage.import_json(json).map_err(::storage_data::__private::import_error)?;
+                Ok(storage)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "use_serde_json"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_storage_json {
+    ($($tokens:tt)*) => {};
 }
\ No newline at end of file
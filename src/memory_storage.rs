@@ -0,0 +1,45 @@
+use crate::StorageKind;
+use alloc::string::String;
+use core::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+std::thread_local! {
+    /// Items of every storage kind, kept in memory off the web browser, such as on native tests.
+    static ITEMS: RefCell<HashMap<(StorageKind, String), String>> = RefCell::new(HashMap::new());
+    /// Amount of items read so far, letting tests tell how many reads an operation takes.
+    static READS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Storage kind whose items the storage kind reaches, as workers reach the same Local Storage
+/// the ``window`` does.
+fn items_kind(storage_kind: &StorageKind) -> StorageKind {
+    match storage_kind {
+        StorageKind::Worker => StorageKind::Local,
+        storage_kind => *storage_kind,
+    }
+}
+
+pub(crate) fn get_item(storage_kind: &StorageKind, key: &str) -> Option<String> {
+    READS.set(READS.get() + 1);
+    ITEMS.with_borrow(|items| items.get(&(items_kind(storage_kind), String::from(key))).cloned())
+}
+
+pub(crate) fn set_item(storage_kind: &StorageKind, key: &str, serialized: String) {
+    ITEMS.with_borrow_mut(|items| {
+        items.insert((items_kind(storage_kind), String::from(key)), serialized)
+    });
+}
+
+pub(crate) fn remove_item(storage_kind: &StorageKind, key: &str) {
+    ITEMS.with_borrow_mut(|items| items.remove(&(items_kind(storage_kind), String::from(key))));
+}
+
+pub(crate) fn len(storage_kind: &StorageKind) -> usize {
+    let storage_kind = items_kind(storage_kind);
+    ITEMS.with_borrow(|items| items.keys().filter(|(kind, _)| *kind == storage_kind).count())
+}
+
+/// Amount of items read from the in-memory storages on this thread so far.
+pub fn reads() -> usize {
+    READS.get()
+}
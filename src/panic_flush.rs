@@ -0,0 +1,53 @@
+use alloc::boxed::Box;
+use core::cell::{Cell, RefCell};
+
+std::thread_local! {
+    /// Function saving the values that must be kept when a panic happens, if one was installed.
+    static PANIC_FLUSH: RefCell<Option<Box<dyn Fn()>>> = RefCell::new(None);
+    static PANIC_HOOK_IS_INSTALLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Invokes the indicated function when a panic happens, right after the panic hook that was set
+/// before, such as the one of ``console_error_panic_hook``, which is meant to save values that
+/// were mutated but not saved yet before the application crashes, as a panic on the web browser
+/// aborts without dropping any value.
+///
+/// As the function must be ``'static``, the values to save are usually shared with it through an
+/// [alloc::rc::Rc] and a [core::cell::RefCell]:
+///
+/// ```rust no_run
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use storage_data::StorageData;
+///
+/// let volume = Rc::new(RefCell::new(StorageData::new("volume", || 100_u8)));
+/// let volume_to_flush = volume.clone();
+/// storage_data::install_panic_flush(move || {
+///     if let Ok(mut volume) = volume_to_flush.try_borrow_mut() {
+///         let _ = volume.save();
+///     }
+/// });
+///
+/// // Saved even if the application panics afterward.
+/// **volume.borrow_mut() = 50;
+/// ```
+///
+/// Saving is best-effort, as the panic might have left the values in an inconsistent state, or
+/// borrowed, hence the ``try_borrow_mut`` above, and serializing or writing them might fail or
+/// panic by itself, in which case the application aborts right away, so the function should do
+/// as little as possible. It only runs for panics on the thread it was installed from, and only
+/// for the first one, while installing another function replaces the previous one.
+pub fn install_panic_flush<F: Fn() + 'static>(flush: F) {
+    PANIC_FLUSH.set(Some(Box::new(flush)));
+    if PANIC_HOOK_IS_INSTALLED.replace(true) {
+        return;
+    }
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        previous_hook(panic_info);
+        let flush = PANIC_FLUSH.try_with(RefCell::take).ok().flatten();
+        if let Some(flush) = flush {
+            flush();
+        }
+    }));
+}
@@ -0,0 +1,17 @@
+use wasm_bindgen_futures::JsFuture;
+use web_sys::js_sys::Promise;
+
+/// Milliseconds waited before the first retry of a write, doubled on every following retry.
+pub(crate) const INITIAL_BACKOFF_MILLIS: i32 = 100;
+
+/// Waits for the milliseconds indicated through ``setTimeout``, returning right away when there is
+/// no ``window`` to set the timeout on, such as off the web browser.
+pub(crate) async fn sleep(millis: i32) {
+    let Some(window) = crate::window() else {
+        return;
+    };
+    let promise = Promise::new(&mut |resolve, _| {
+        let _ = window.set_timeout_with_callback_and_timeout_and_arguments_0(&resolve, millis);
+    });
+    let _ = JsFuture::from(promise).await;
+}
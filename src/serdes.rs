@@ -1,12 +1,25 @@
 use alloc::boxed::Box;
 use alloc::format;
+use alloc::rc::Rc;
 use alloc::string::String;
+use alloc::vec::Vec;
 use core::error::Error;
-#[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+#[cfg(any(
+    feature = "use_serde_bincode",
+    feature = "use_serde_cbor",
+    feature = "compress",
+    feature = "indexeddb"
+))]
 use base64::Engine;
 use crate::log_error::LogError;
+use crate::Migration;
 
-#[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+#[cfg(any(
+    feature = "use_serde_bincode",
+    feature = "use_serde_cbor",
+    feature = "compress",
+    feature = "indexeddb"
+))]
 const GENERAL_PURPOSE_ENCODER: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
 
@@ -17,6 +30,13 @@ pub(crate) fn serialize_json<Value: serde::Serialize>(value: &Value)
         .map_log_possible_error(|err| format!("Cannot serialize as json due to {err:?}"))
 }
 
+#[cfg(feature = "use_serde_json")]
+pub(crate) fn serialize_json_pretty<Value: serde::Serialize>(value: &Value)
+    -> Result<String, Box<dyn Error>> {
+    serde_json::to_string_pretty(&value)
+        .map_log_possible_error(|err| format!("Cannot serialize as pretty json due to {err:?}"))
+}
+
 #[cfg(feature = "use_serde_json")]
 pub(crate) fn deserialize_json<Value: for<'de> serde::de::Deserialize<'de>>(serialized: String)
     -> Result<Value, Box<dyn Error>> {
@@ -27,9 +47,14 @@ pub(crate) fn deserialize_json<Value: for<'de> serde::de::Deserialize<'de>>(seri
 #[cfg(feature = "use_serde_bincode")]
 pub(crate) fn serialize_bincode<Value: serde::Serialize>(value: &Value)
     -> Result<String, Box<dyn Error>> {
-    let deserialized = bincode::serialize(&value)
-        .map_log_possible_error(|err| format!("Cannot serialize as bincode due to {err:?}"))?;
-    Ok(GENERAL_PURPOSE_ENCODER.encode(deserialized))
+    Ok(GENERAL_PURPOSE_ENCODER.encode(serialize_bincode_bytes(value)?))
+}
+
+#[cfg(feature = "use_serde_bincode")]
+pub(crate) fn serialize_bincode_bytes<Value: serde::Serialize>(value: &Value)
+    -> Result<Vec<u8>, Box<dyn Error>> {
+    bincode::serialize(&value)
+        .map_log_possible_error(|err| format!("Cannot serialize as bincode due to {err:?}"))
 }
 
 #[cfg(feature = "use_serde_bincode")]
@@ -38,7 +63,14 @@ pub(crate) fn deserialize_bincode<Value: for<'de> serde::de::Deserialize<'de>>(s
     let serialized = GENERAL_PURPOSE_ENCODER.decode(serialized.as_bytes())
         .map_log_possible_error(|err|
             format!("Cannot decode on deserialization of bincode due to {err:?}"))?;
-    bincode::deserialize(&*serialized)
+    deserialize_bincode_bytes(&serialized)
+}
+
+#[cfg(feature = "use_serde_bincode")]
+pub(crate) fn deserialize_bincode_bytes<Value: for<'de> serde::de::Deserialize<'de>>(
+    serialized: &[u8],
+) -> Result<Value, Box<dyn Error>> {
+    bincode::deserialize(serialized)
         .map_log_possible_error(|err| format!("Cannot deserialize as bincode due to {err:?}"))
 }
 
@@ -73,10 +105,16 @@ pub(crate) fn deserialize_ron<Value: for<'de> serde::de::Deserialize<'de>>(seria
 #[cfg(feature = "use_serde_cbor")]
 pub(crate) fn serialize_cbor<Value: serde::Serialize>(value: &Value)
     -> Result<String, Box<dyn Error>> {
-    let mut deserialized = alloc::vec::Vec::new();
+    Ok(GENERAL_PURPOSE_ENCODER.encode(serialize_cbor_bytes(value)?))
+}
+
+#[cfg(feature = "use_serde_cbor")]
+pub(crate) fn serialize_cbor_bytes<Value: serde::Serialize>(value: &Value)
+    -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut deserialized = Vec::new();
     ciborium::ser::into_writer(&value, &mut deserialized)
         .map_log_possible_error(|err| format!("Could deserialize as CBOR due to: {err:?}"))?;
-    Ok(GENERAL_PURPOSE_ENCODER.encode(deserialized))
+    Ok(deserialized)
 }
 
 #[cfg(feature = "use_serde_cbor")]
@@ -85,6 +123,426 @@ pub(crate) fn deserialize_cbor<Value: for<'de> serde::de::Deserialize<'de>>(seri
     let serialized = GENERAL_PURPOSE_ENCODER.decode(serialized.as_bytes())
         .map_log_possible_error(|err|
             format!("Cannot decode on deserialization of bincode due to {err:?}"))?;
-    ciborium::de::from_reader(&*serialized)
+    deserialize_cbor_bytes(&serialized)
+}
+
+#[cfg(feature = "use_serde_cbor")]
+pub(crate) fn deserialize_cbor_bytes<Value: for<'de> serde::de::Deserialize<'de>>(
+    serialized: &[u8],
+) -> Result<Value, Box<dyn Error>> {
+    ciborium::de::from_reader(serialized)
         .map_log_possible_error(|err| format!("Cannot deserialize as CBOR due to {err:?}"))
+}
+
+pub(crate) fn serialize_enum_as_int<Value: Clone + Into<u32>>(value: &Value)
+    -> Result<String, Box<dyn Error>> {
+    let discriminant: u32 = value.clone().into();
+    Ok(format!("{discriminant}"))
+}
+
+pub(crate) fn deserialize_enum_as_int<Value: TryFrom<u32>>(serialized: String)
+    -> Result<Value, Box<dyn Error>> {
+    let discriminant = serialized.parse::<u32>()
+        .map_log_possible_error(|err| format!("Cannot deserialize as integer due to {err:?}"))?;
+    Value::try_from(discriminant)
+        .map_log_possible_error(|_| format!("{discriminant} is not a discriminant of this enum"))
+}
+
+pub(crate) fn serialize_raw_string<Value: AsRef<str>>(value: &Value)
+    -> Result<String, Box<dyn Error>> {
+    Ok(String::from(value.as_ref()))
+}
+
+pub(crate) fn deserialize_raw_string<Value: From<String>>(serialized: String)
+    -> Result<Value, Box<dyn Error>> {
+    Ok(Value::from(serialized))
+}
+
+/// Maps every byte to the character of the same code point, as in Latin-1, so each byte takes a
+/// single UTF-16 code unit of the Storage.
+pub(crate) fn serialize_latin1_bytes<Value: AsRef<[u8]>>(value: &Value)
+    -> Result<String, Box<dyn Error>> {
+    Ok(value.as_ref().iter().map(|byte| char::from(*byte)).collect())
+}
+
+pub(crate) fn deserialize_latin1_bytes<Value: From<Vec<u8>>>(serialized: String)
+    -> Result<Value, Box<dyn Error>> {
+    serialized.chars()
+        .map(u8::try_from)
+        .collect::<Result<Vec<u8>, _>>()
+        .map(Value::from)
+        .map_log_possible_error(|err| format!("Cannot deserialize as Latin-1 bytes due to {err:?}"))
+}
+
+/// Turns the base64 serialized value of a binary serde format into a string where every byte is
+/// kept as the character of the same code point, being every character in the Latin-1 range.
+#[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+pub(crate) fn base64_to_latin1(serialized: String) -> Result<String, Box<dyn Error>> {
+    let bytes = GENERAL_PURPOSE_ENCODER.decode(serialized.as_bytes())
+        .map_log_possible_error(|err| format!("Cannot decode base64 as Latin-1 due to {err:?}"))?;
+    Ok(bytes.into_iter().map(char::from).collect())
+}
+
+/// Turns a string written by [base64_to_latin1] back into its base64 form.
+#[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+pub(crate) fn latin1_to_base64(serialized: String) -> Result<String, Box<dyn Error>> {
+    let bytes = serialized
+        .chars()
+        .map(u8::try_from)
+        .collect::<Result<Vec<u8>, _>>()
+        .map_log_possible_error(|err| format!("Value is not Latin-1 due to {err:?}"))?;
+    Ok(GENERAL_PURPOSE_ENCODER.encode(bytes))
+}
+
+#[cfg(feature = "compress")]
+const COMPRESSED_PREFIX: &str = "\u{1}DFL:";
+
+#[cfg(feature = "compress")]
+pub(crate) fn compress(serialized: String) -> String {
+    let compressed = miniz_oxide::deflate::compress_to_vec(serialized.as_bytes(), 6);
+    format!("{COMPRESSED_PREFIX}{}", GENERAL_PURPOSE_ENCODER.encode(compressed))
+}
+
+#[cfg(feature = "compress")]
+pub(crate) fn decompress(serialized: String) -> Result<String, Box<dyn Error>> {
+    let Some(compressed) = serialized.strip_prefix(COMPRESSED_PREFIX) else {
+        return Ok(serialized);
+    };
+    let compressed = GENERAL_PURPOSE_ENCODER.decode(compressed.as_bytes())
+        .map_log_possible_error(|err|
+            format!("Cannot decode on decompression due to {err:?}"))?;
+    let decompressed = miniz_oxide::inflate::decompress_to_vec(&compressed)
+        .map_log_possible_error(|err| format!("Cannot decompress due to {err:?}"))?;
+    String::from_utf8(decompressed)
+        .map_log_possible_error(|err| format!("Decompressed value is not UTF-8 due to {err:?}"))
+}
+
+const VERSION_PREFIX: &str = "\u{2}v";
+
+pub(crate) fn prepend_version(version: u32, serialized: String) -> String {
+    format!("{VERSION_PREFIX}{version}:{serialized}")
+}
+
+/// Splits the version header from the serialized value, values without header are considered as
+/// version 0.
+pub(crate) fn split_version(serialized: String) -> (u32, String) {
+    let version_and_payload = serialized
+        .strip_prefix(VERSION_PREFIX)
+        .and_then(|header_and_payload| header_and_payload.split_once(':'))
+        .and_then(|(version, payload)| Some((version.parse().ok()?, payload)));
+    match version_and_payload {
+        Some((version, payload)) => (version, String::from(payload)),
+        None => (0, serialized),
+    }
+}
+
+const EXPIRY_PREFIX: &str = "\u{3}e";
+
+pub(crate) fn prepend_expiry(expires_at_ms: u64, serialized: String) -> String {
+    format!("{EXPIRY_PREFIX}{expires_at_ms}:{serialized}")
+}
+
+/// Splits the expiry header from the serialized value, values without header never expire.
+pub(crate) fn split_expiry(serialized: &str) -> (Option<u64>, &str) {
+    let expiry_and_payload = serialized
+        .strip_prefix(EXPIRY_PREFIX)
+        .and_then(|header_and_payload| header_and_payload.split_once(':'))
+        .and_then(|(expires_at_ms, payload)| Some((expires_at_ms.parse().ok()?, payload)));
+    match expiry_and_payload {
+        Some((expires_at_ms, payload)) => (Some(expires_at_ms), payload),
+        None => (None, serialized),
+    }
+}
+
+/// Serialized value as kept by a storage kind, being raw bytes only on storage kinds able to keep
+/// them, such as [crate::StorageKind::IndexedDb], and when using binary serde formats, such as
+/// bincode's and CBOR's, which are otherwise encoded as base64 text.
+#[cfg(feature = "indexeddb")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SerializedPayload {
+    /// Value serialized as text, as kept on every storage kind.
+    Text(String),
+    /// Value serialized as raw bytes by a binary serde format.
+    Binary(Vec<u8>),
+}
+
+#[cfg(feature = "indexeddb")]
+impl SerializedPayload {
+    /// Gets the value as text, encoding raw bytes as base64, just like binary serde formats are
+    /// kept on storage kinds unable to keep bytes.
+    pub fn into_text(self) -> String {
+        match self {
+            SerializedPayload::Text(serialized) => serialized,
+            SerializedPayload::Binary(bytes) => GENERAL_PURPOSE_ENCODER.encode(bytes),
+        }
+    }
+}
+
+/// Serializer and deserializer of a binary serde format working over raw bytes rather than over
+/// their base64 encoding.
+#[cfg(feature = "indexeddb")]
+pub(crate) type BinarySerde<Value> = (
+    fn(&Value) -> Result<Vec<u8>, Box<dyn Error>>,
+    fn(&[u8]) -> Result<Value, Box<dyn Error>>,
+);
+
+/// Serialization and deserialization of a glue's value, including every layer set over them, such
+/// as compression, versioning or expiration.
+///
+/// The serializer and deserializer are either function pointers, which can be set on const
+/// contexts, or closures capturing state, being the latter only used if they were set after the
+/// former, as told by ``serialize_with_closure`` and ``deserialize_with_closure``.
+pub(crate) struct Codec<Value> {
+    pub(crate) serialize_as: fn(&Value) -> Result<String, Box<dyn Error>>,
+    pub(crate) deserialize_as: fn(String) -> Result<Value, Box<dyn Error>>,
+    pub(crate) serialize_closure: Option<SerializeClosure<Value>>,
+    pub(crate) deserialize_closure: Option<DeserializeClosure<Value>>,
+    pub(crate) serialize_with_closure: bool,
+    pub(crate) deserialize_with_closure: bool,
+    #[cfg(feature = "compress")]
+    pub(crate) compressed: bool,
+    #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+    pub(crate) binary_as_latin1: bool,
+    /// Tells whether the serializer writes base64, as bincode's and CBOR's do, which is required
+    /// by ``binary_as_latin1``.
+    #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+    pub(crate) serializes_as_base64: bool,
+    pub(crate) version: Option<(u32, Migration)>,
+    pub(crate) ttl_ms: Option<u64>,
+    pub(crate) lenient_string: Option<fn(String) -> Value>,
+    #[cfg(feature = "indexeddb")]
+    pub(crate) binary: Option<BinarySerde<Value>>,
+}
+
+/// Serializer capturing state, such as an encryption key or a format chosen at runtime.
+pub(crate) type SerializeClosure<Value> = Rc<dyn Fn(&Value) -> Result<String, Box<dyn Error>>>;
+
+/// Deserializer capturing state, such as an encryption key or a format chosen at runtime.
+pub(crate) type DeserializeClosure<Value> = Rc<dyn Fn(String) -> Result<Value, Box<dyn Error>>>;
+
+impl<Value> Clone for Codec<Value> {
+    fn clone(&self) -> Self {
+        Self {
+            serialize_as: self.serialize_as,
+            deserialize_as: self.deserialize_as,
+            serialize_closure: self.serialize_closure.clone(),
+            deserialize_closure: self.deserialize_closure.clone(),
+            serialize_with_closure: self.serialize_with_closure,
+            deserialize_with_closure: self.deserialize_with_closure,
+            #[cfg(feature = "compress")]
+            compressed: self.compressed,
+            #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+            binary_as_latin1: self.binary_as_latin1,
+            #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+            serializes_as_base64: self.serializes_as_base64,
+            version: self.version,
+            ttl_ms: self.ttl_ms,
+            lenient_string: self.lenient_string,
+            #[cfg(feature = "indexeddb")]
+            binary: self.binary,
+        }
+    }
+}
+
+impl<Value> Codec<Value>
+where
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates a codec using the default serde format, without any layer over it.
+    pub(crate) const fn new() -> Self {
+        Self {
+            serialize_closure: None,
+            deserialize_closure: None,
+            serialize_with_closure: false,
+            deserialize_with_closure: false,
+            #[cfg(feature = "compress")]
+            compressed: false,
+            #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+            binary_as_latin1: false,
+            #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+            serializes_as_base64: cfg!(any(
+                feature = "default_serde_bincode",
+                feature = "default_serde_cbor"
+            )),
+            version: None,
+            ttl_ms: None,
+            lenient_string: None,
+            #[cfg(all(
+                feature = "indexeddb",
+                not(any(feature = "default_serde_bincode", feature = "default_serde_cbor"))
+            ))]
+            binary: None,
+
+            #[cfg(feature = "default_serde_json")]
+            serialize_as: serialize_json,
+            #[cfg(feature = "default_serde_json")]
+            deserialize_as: deserialize_json,
+
+            #[cfg(feature = "default_serde_bincode")]
+            serialize_as: serialize_bincode,
+            #[cfg(feature = "default_serde_bincode")]
+            deserialize_as: deserialize_bincode,
+            #[cfg(all(feature = "indexeddb", feature = "default_serde_bincode"))]
+            binary: Some((serialize_bincode_bytes, deserialize_bincode_bytes)),
+
+            #[cfg(feature = "default_serde_yaml")]
+            serialize_as: serialize_yaml,
+            #[cfg(feature = "default_serde_yaml")]
+            deserialize_as: deserialize_yaml,
+
+            #[cfg(feature = "default_serde_ron")]
+            serialize_as: serialize_ron,
+            #[cfg(feature = "default_serde_ron")]
+            deserialize_as: deserialize_ron,
+
+            #[cfg(feature = "default_serde_cbor")]
+            serialize_as: serialize_cbor,
+            #[cfg(feature = "default_serde_cbor")]
+            deserialize_as: deserialize_cbor,
+            #[cfg(all(feature = "indexeddb", feature = "default_serde_cbor"))]
+            binary: Some((serialize_cbor_bytes, deserialize_cbor_bytes)),
+        }
+    }
+}
+
+impl<Value> Codec<Value> {
+    /// Serializes the value through the serializer and any layer set over it.
+    pub(crate) fn serialize(&self, value: &Value) -> Result<String, Box<dyn Error>> {
+        let serialized = match (&self.serialize_closure, self.serialize_with_closure) {
+            (Some(serialize), true) => serialize(value)?,
+            _ => (self.serialize_as)(value)?,
+        };
+        #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+        let serialized = if self.binary_as_latin1 {
+            base64_to_latin1(serialized)?
+        } else {
+            serialized
+        };
+        #[cfg(feature = "compress")]
+        let serialized = if self.compressed {
+            compress(serialized)
+        } else {
+            serialized
+        };
+        let serialized = match self.version {
+            Some((version, _)) => prepend_version(version, serialized),
+            None => serialized,
+        };
+        let serialized = match self.ttl_ms {
+            Some(ttl_ms) => {
+                let expires_at_ms = crate::now_ms() as u64 + ttl_ms;
+                prepend_expiry(expires_at_ms, serialized)
+            }
+            None => serialized,
+        };
+        Ok(serialized)
+    }
+
+    /// Deserializes the value through any layer set over the deserializer and the deserializer,
+    /// also telling whether the value was migrated from an older version.
+    pub(crate) fn deserialize(&self, serialized: String) -> Result<(Value, bool), Box<dyn Error>> {
+        let serialized = match self.ttl_ms {
+            Some(_) => String::from(split_expiry(&serialized).1),
+            None => serialized,
+        };
+        let (stored_version, serialized) = match self.version {
+            Some(_) => {
+                let (stored_version, serialized) = split_version(serialized);
+                (Some(stored_version), serialized)
+            }
+            None => (None, serialized),
+        };
+        #[cfg(feature = "compress")]
+        let serialized = if self.compressed {
+            decompress(serialized)?
+        } else {
+            serialized
+        };
+        let (serialized, migrated) = match (self.version, stored_version) {
+            (Some((version, migrate)), Some(stored_version)) if version != stored_version => {
+                let migrated = migrate(stored_version, serialized).map_log_possible_error(|err| {
+                    format!("Could not migrate value from version {stored_version} due to {err}")
+                })?;
+                (migrated, true)
+            }
+            _ => (serialized, false),
+        };
+        #[cfg(any(feature = "use_serde_bincode", feature = "use_serde_cbor"))]
+        let serialized = if self.binary_as_latin1 {
+            latin1_to_base64(serialized)?
+        } else {
+            serialized
+        };
+        let raw = self.lenient_string.map(|lenient_string| (lenient_string, serialized.clone()));
+        let value = match (&self.deserialize_closure, self.deserialize_with_closure) {
+            (Some(deserialize), true) => deserialize(serialized),
+            _ => (self.deserialize_as)(serialized),
+        };
+        let value = match (value, raw) {
+            (Err(_), Some((lenient_string, raw))) => lenient_string(raw),
+            (value, _) => value?,
+        };
+        Ok((value, migrated))
+    }
+
+    /// Serializes the value as raw bytes if the serde format is binary and no layer is set over
+    /// it, as every layer is kept as text, or as text otherwise, see [Codec::serialize].
+    #[cfg(feature = "indexeddb")]
+    pub(crate) fn serialize_payload(&self, value: &Value)
+        -> Result<SerializedPayload, Box<dyn Error>> {
+        match self.binary.filter(|_| self.is_layerless()) {
+            Some((serialize, _)) => Ok(SerializedPayload::Binary(serialize(value)?)),
+            None => Ok(SerializedPayload::Text(self.serialize(value)?)),
+        }
+    }
+
+    /// Deserializes raw bytes straight through the binary serde format, while text and bytes
+    /// written by other formats go through [Codec::deserialize].
+    #[cfg(feature = "indexeddb")]
+    pub(crate) fn deserialize_payload(&self, payload: SerializedPayload)
+        -> Result<(Value, bool), Box<dyn Error>> {
+        match (payload, self.binary.filter(|_| !self.deserialize_with_closure)) {
+            (SerializedPayload::Binary(bytes), Some((_, deserialize))) => {
+                Ok((deserialize(&bytes)?, false))
+            }
+            (payload, _) => self.deserialize(payload.into_text()),
+        }
+    }
+
+    /// Tells whether the value is serialized by the serializer alone, without any layer over it.
+    #[cfg(feature = "indexeddb")]
+    fn is_layerless(&self) -> bool {
+        #[cfg(feature = "compress")]
+        if self.compressed {
+            return false;
+        }
+        !self.serialize_with_closure && self.version.is_none() && self.ttl_ms.is_none()
+    }
+
+    /// Checks the serialized value deserializes back into the value it was serialized from.
+    pub(crate) fn verify_roundtrip(&self, value: &Value, serialized: &str)
+        -> Result<(), Box<dyn Error>>
+    where
+        Value: PartialEq,
+    {
+        let (deserialized, _) = self.deserialize(String::from(serialized))?;
+        if deserialized != *value {
+            return None::<()>.map_log_possible_error(|_| {
+                format!("Value deserialized from {serialized} differs from the one serialized")
+            });
+        }
+        Ok(())
+    }
+
+    /// Tells whether the serialized value has expired, values of codecs without a time to live
+    /// never expire.
+    pub(crate) fn expired(&self, serialized: &str) -> bool {
+        if self.ttl_ms.is_none() {
+            return false;
+        }
+        match split_expiry(serialized) {
+            (Some(expires_at_ms), _) => expires_at_ms as f64 <= crate::now_ms(),
+            (None, _) => false,
+        }
+    }
 }
\ No newline at end of file
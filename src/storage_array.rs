@@ -0,0 +1,77 @@
+use crate::{StorageData, StorageError, StorageKind};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Fixed amount of glues of the same type, stored under keys made of a prefix followed by the
+/// index of the glue, such as ``prefix0``, ``prefix1``, and so on, this is useful for many
+/// similar values, such as feature flags or the cells of a grid, which would otherwise require a
+/// field for each of them, see [crate::storage_array!].
+pub struct StorageArray<Value, const N: usize>
+where
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    glues: [StorageData<String, Value>; N],
+}
+
+impl<Value, const N: usize> StorageArray<Value, N>
+where
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates the glues of the indicated storage kind, under keys starting by the indicated
+    /// prefix, where every glue gets the indicated default value when it isn't set in the storage.
+    pub fn new(storage_kind: StorageKind, prefix: &str, default: fn() -> Value) -> Self {
+        Self {
+            glues: core::array::from_fn(|index| {
+                StorageData::new(format!("{prefix}{index}"), default).with_storage(storage_kind)
+            }),
+        }
+    }
+
+    /// Amount of glues.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Tells whether there are no glues.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Gets the current value of the glue at the indicated index, see [StorageData::get].
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get(&self, index: usize) -> &Value {
+        self.glues[index].get()
+    }
+
+    /// Gets the current value of the glue at the indicated index, see [StorageData::get_mut].
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> &mut Value {
+        &mut self.glues[index]
+    }
+
+    /// Sets the value of the glue at the indicated index for both the glue and the Storage, see
+    /// [StorageData::set].
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn set(&mut self, index: usize, value: Value) -> Result<(), StorageError> {
+        self.glues[index].set(value)
+    }
+
+    /// Saves the value of every glue, and returns the indexes of those that failed.
+    pub fn save_all(&mut self) -> Result<(), Vec<usize>> {
+        let failed_indexes = self
+            .glues
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, glue)| glue.save().is_err().then_some(index))
+            .collect::<Vec<_>>();
+        if failed_indexes.is_empty() {
+            Ok(())
+        } else {
+            Err(failed_indexes)
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{StorageKind, DEFAULT_STORAGE_KIND};
+
+/// Storage a [crate::StorageData] targets to, known at the type level, so functions can demand
+/// glues over a specific storage, such as ``StorageData<_, _, Session>`` for values that must not
+/// outlive the tab:
+///
+/// ```rust no_run
+/// use storage_data::{Session, StorageData};
+///
+/// fn remember_draft(draft: &mut StorageData<&str, String, Session>) {
+///     draft.set(String::from("Dear ...")).unwrap();
+/// }
+///
+/// let mut draft = StorageData::session("draft", String::new);
+/// remember_draft(&mut draft);
+/// ```
+///
+/// Glues are [Dynamic] by default, where the storage is chosen at runtime through
+/// [crate::StorageData::with_storage], while [Local] and [Session] glues are created through
+/// [crate::StorageData::local] and [crate::StorageData::session], and their storage can't change.
+pub trait StorageBackend {
+    /// Storage glues of this backend start targeting to.
+    const STORAGE_KIND: StorageKind;
+}
+
+/// Backend of glues whose storage is chosen at runtime, starting with [DEFAULT_STORAGE_KIND].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Dynamic;
+
+/// Backend of glues always targeting Local Storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Local;
+
+/// Backend of glues always targeting Session Storage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Session;
+
+impl StorageBackend for Dynamic {
+    const STORAGE_KIND: StorageKind = DEFAULT_STORAGE_KIND;
+}
+
+impl StorageBackend for Local {
+    const STORAGE_KIND: StorageKind = StorageKind::Local;
+}
+
+impl StorageBackend for Session {
+    const STORAGE_KIND: StorageKind = StorageKind::Session;
+}
@@ -0,0 +1,85 @@
+use crate::{Dynamic, StorageBackend, StorageData};
+use core::ops::{Deref, DerefMut};
+
+/// Guard over the value of a glue, as given by [StorageData::entry], which saves the value once
+/// the guard is dropped, but only if it was mutably dereferenced, scoping the save to a block
+/// rather than to the whole lifetime of the glue:
+///
+/// ```rust no_run
+/// use storage_data::StorageData;
+///
+/// let mut volume = StorageData::new("volume", || 100_u8);
+/// {
+///     let mut entry = volume.entry();
+///     *entry = 50;
+/// } // The value is saved here.
+/// # core::mem::forget(volume);
+/// ```
+pub struct StorageEntry<'data, Key, Value, Backend = Dynamic>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    data: &'data mut StorageData<Key, Value, Backend>,
+    modified: bool,
+}
+
+impl<'data, Key, Value, Backend> StorageEntry<'data, Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    pub(crate) fn new(data: &'data mut StorageData<Key, Value, Backend>) -> Self {
+        Self {
+            data,
+            modified: false,
+        }
+    }
+}
+
+/// Dereferences to the glue's current value through a call to [StorageData::get].
+impl<Key, Value, Backend> Deref for StorageEntry<'_, Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    type Target = Value;
+
+    fn deref(&self) -> &Self::Target {
+        self.data.get()
+    }
+}
+
+impl<Key, Value, Backend> DerefMut for StorageEntry<'_, Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    /// Dereferences to the glue's current value through a call to [StorageData::get_mut].
+    ///
+    /// Calling this marks the value as modified, so it's saved once this guard is dropped, even
+    /// if the value doesn't mutate in the end.
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.modified = true;
+        self.data.mutated = true;
+        self.data.get_mut()
+    }
+}
+
+/// Upon drop, the value is saved if it was mutably dereferenced.
+impl<Key, Value, Backend> Drop for StorageEntry<'_, Key, Value, Backend>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    Backend: StorageBackend,
+{
+    fn drop(&mut self) {
+        if self.modified {
+            let _ = self.data.save();
+        }
+    }
+}
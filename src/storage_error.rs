@@ -0,0 +1,69 @@
+use alloc::boxed::Box;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use alloc::format;
+use wasm_bindgen::JsValue;
+
+/// Reason why a value could not be set on a Storage, allowing to tell apart a full Storage, on
+/// which the user could be prompted to free space, from other failures.
+#[derive(Debug)]
+pub enum StorageError {
+    /// The Storage is full, as the web browser threw a ``QuotaExceededError``.
+    QuotaExceeded,
+    /// The value could not be serialized.
+    SerdeSerialize(Box<dyn Error>),
+    /// The value stored could not be deserialized, such as when it's corrupt or it was stored
+    /// with a different type or serde format.
+    SerdeDeserialize(Box<dyn Error>),
+    /// The Storage could not be reached or it refused the operation for a reason other than being
+    /// full, such as when it's disabled by the web browser.
+    StorageUnavailable(Box<dyn Error>),
+    /// The Web Storage API threw an exception other than ``QuotaExceededError``, described by
+    /// the indicated text.
+    Js(String),
+}
+
+impl StorageError {
+    /// Turns the error thrown by the Web Storage API when setting a value into a [StorageError],
+    /// telling apart a full Storage from other failures.
+    pub(crate) fn from_js(error: JsValue) -> Self {
+        if Self::is_quota_exceeded(&error) {
+            StorageError::QuotaExceeded
+        } else {
+            StorageError::Js(format!("{error:?}"))
+        }
+    }
+
+    /// Tells whether the error thrown by the Web Storage API is a ``QuotaExceededError``, also
+    /// recognizing the name older versions of Firefox used for it.
+    fn is_quota_exceeded(error: &JsValue) -> bool {
+        let name = web_sys::js_sys::Reflect::get(error, &JsValue::from_str("name"))
+            .ok()
+            .and_then(|name| name.as_string());
+        matches!(
+            name.as_deref(),
+            Some("QuotaExceededError" | "NS_ERROR_DOM_QUOTA_REACHED")
+        )
+    }
+}
+
+impl Display for StorageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StorageError::QuotaExceeded => f.write_str("The Storage is full"),
+            StorageError::SerdeSerialize(error) => {
+                write!(f, "The value could not be serialized due to:\n{error}")
+            }
+            StorageError::SerdeDeserialize(error) => {
+                write!(f, "The value could not be deserialized due to:\n{error}")
+            }
+            StorageError::StorageUnavailable(error) => {
+                write!(f, "The Storage is unavailable due to:\n{error}")
+            }
+            StorageError::Js(error) => write!(f, "The Storage threw:\n{error}"),
+        }
+    }
+}
+
+impl Error for StorageError {}
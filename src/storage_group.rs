@@ -0,0 +1,102 @@
+use crate::json_export::{insert_json, JsonMap};
+use crate::log_error::LogError;
+use crate::{StorageData, StorageError, StorageKind};
+use alloc::boxed::Box;
+use alloc::format;
+use core::error::Error;
+
+/// Many values kept under a single key of the Storage as a JSON object, where every value is
+/// stored under its own subkey, this keeps down the amount of keys of apps with hundreds of small
+/// settings, which would otherwise take a key each:
+///
+/// ```rust no_run
+/// use storage_data::StorageGroup;
+///
+/// let mut settings = StorageGroup::new("settings");
+/// settings.set_field("volume", &50_u8).unwrap();
+/// settings.set_field("theme", &"dark").unwrap();
+///
+/// // Both values are written at once under the key 'settings'.
+/// settings.save().unwrap();
+/// assert_eq!(settings.field::<u8>("volume").unwrap(), Some(50));
+/// ```
+///
+/// The object is read from the Storage once, when a field is first accessed, while every field
+/// is only deserialized when it's read, and just like a [StorageData], the object is saved when
+/// the group is dropped if any field was set or removed.
+pub struct StorageGroup<Key>
+where
+    Key: AsRef<str>,
+{
+    data: StorageData<Key, JsonMap>,
+}
+
+impl<Key> StorageGroup<Key>
+where
+    Key: AsRef<str>,
+{
+    /// Creates a group stored under the indicated key, as a JSON object regardless of the
+    /// default serde format.
+    pub const fn new(key: Key) -> Self {
+        Self {
+            data: StorageData::new(key, JsonMap::new).serde_json(),
+        }
+    }
+
+    /// Specifies the kind of storage this group targets to.
+    pub const fn with_storage(mut self, storage_kind: StorageKind) -> Self {
+        self.data.storage_kind = storage_kind;
+        self
+    }
+
+    /// Gets the value of the indicated subkey, or ``None`` if the group doesn't contain it.
+    ///
+    /// This fails if the value stored under the subkey can't be deserialized as the requested
+    /// type, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    pub fn field<Value>(&self, subkey: &str) -> Result<Option<Value>, Box<dyn Error>>
+    where
+        Value: for<'de> serde::de::Deserialize<'de>,
+    {
+        self.data
+            .get()
+            .get(subkey)
+            .map(|value| {
+                Value::deserialize(value).map_log_possible_error(|err| {
+                    format!("Cannot deserialize field {subkey} of group due to {err:?}")
+                })
+            })
+            .transpose()
+    }
+
+    /// Tells whether the group contains the indicated subkey.
+    pub fn contains_field(&self, subkey: &str) -> bool {
+        self.data.get().contains_key(subkey)
+    }
+
+    /// Sets the value of the indicated subkey, which isn't written to the Storage until the group
+    /// is saved, so many fields can be set taking a single write.
+    ///
+    /// This fails if the value can't be serialized as JSON, returning an explanation to this
+    /// through a [StorageError].
+    pub fn set_field<Value>(&mut self, subkey: &str, value: &Value) -> Result<(), StorageError>
+    where
+        Value: serde::Serialize,
+    {
+        insert_json(self.data.get_mut(), subkey, value).map_err(StorageError::SerdeSerialize)?;
+        self.data.mutated = true;
+        Ok(())
+    }
+
+    /// Removes the indicated subkey, telling whether the group contained it, which isn't written
+    /// to the Storage until the group is saved.
+    pub fn remove_field(&mut self, subkey: &str) -> bool {
+        let removed = self.data.get_mut().remove(subkey).is_some();
+        self.data.mutated |= removed;
+        removed
+    }
+
+    /// Writes every field to the Storage at once, see [StorageData::save].
+    pub fn save(&mut self) -> Result<(), StorageError> {
+        self.data.save()
+    }
+}
@@ -0,0 +1,128 @@
+use crate::log_error::LogError;
+use crate::serdes::Codec;
+use crate::{StorageKind, DEFAULT_STORAGE_KIND};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::error::Error;
+use core::marker::PhantomData;
+
+/// Append-only log of values stored in a Local/Session Storage, where every value is stored under
+/// its own key, being these keys the prefix followed by the index of the value, such as
+/// ``prefix.0``, ``prefix.1``, and so on, while the amount of values is kept under the key
+/// ``prefix.next``.
+///
+/// Unlike storing a [Vec] in a [crate::StorageData], appending a value only writes said value,
+/// rather than rewriting the whole log, making it suitable for big logs, such as an offline queue
+/// of events that are sent to a server later.
+pub struct StorageLog<Key, Value>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    storage_kind: StorageKind,
+    prefix: Key,
+    codec: Codec<Value>,
+    values: PhantomData<Value>,
+}
+
+impl<Key, Value> StorageLog<Key, Value>
+where
+    Key: AsRef<str>,
+    Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+{
+    /// Creates a log whose values are stored under keys starting by the indicated prefix.
+    pub const fn new(prefix: Key) -> Self {
+        Self {
+            storage_kind: DEFAULT_STORAGE_KIND,
+            prefix,
+            values: PhantomData,
+            codec: Codec::new(),
+        }
+    }
+
+    /// Specifies the kind of storage this log targets to, being this either Local or Session.
+    pub const fn with_storage(mut self, storage_kind: StorageKind) -> Self {
+        self.storage_kind = storage_kind;
+        self
+    }
+
+    /// Specifies how the values are serialized when setting them on the Storage and how they are
+    /// deserialized when retrieving them from the Storage.
+    pub const fn serde_with(
+        mut self,
+        serialize: fn(&Value) -> Result<String, Box<dyn Error>>,
+        deserialize: fn(String) -> Result<Value, Box<dyn Error>>,
+    ) -> Self {
+        self.codec.serialize_as = serialize;
+        self.codec.deserialize_as = deserialize;
+        self.codec.serialize_with_closure = false;
+        self.codec.deserialize_with_closure = false;
+        self
+    }
+
+    fn counter_key(&self) -> String {
+        format!("{}.next", self.prefix.as_ref())
+    }
+
+    fn value_key(&self, index: usize) -> String {
+        format!("{}.{index}", self.prefix.as_ref())
+    }
+
+    /// Amount of values in the log.
+    pub fn len(&self) -> Result<usize, Box<dyn Error>> {
+        let counter_key = self.counter_key();
+        match self.storage_kind.get_item(&counter_key)? {
+            None => Ok(0),
+            Some(len) => len.parse().map_log_possible_error(|err| {
+                format!("Could not read length of log from key {counter_key} due to {err:?}")
+            }),
+        }
+    }
+
+    /// Tells whether the log has no values.
+    pub fn is_empty(&self) -> Result<bool, Box<dyn Error>> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Appends a value at the end of the log, writing only the value and the amount of values.
+    ///
+    /// This might fail if the value could not be serialized, or if the quota's limit is reached,
+    /// returning an explanation to this through an ``Err<Box<dyn Error>>``.
+    pub fn append(&self, value: Value) -> Result<(), Box<dyn Error>> {
+        let index = self.len()?;
+        crate::set_data(
+            &self.storage_kind,
+            self.value_key(index),
+            &value,
+            |value| self.codec.serialize(value),
+        )?;
+        self.storage_kind
+            .set_item(&self.counter_key(), || Ok((index + 1).to_string()))
+    }
+
+    /// Reads every value in the log in the order they were appended, and removes them from the
+    /// Storage.
+    ///
+    /// If any value could not be deserialized, none of them are removed, and an explanation to
+    /// this is returned through an ``Err<Box<dyn Error>>``.
+    pub fn drain(&self) -> Result<Vec<Value>, Box<dyn Error>> {
+        let len = self.len()?;
+        let mut values = Vec::with_capacity(len);
+        for index in 0..len {
+            let Some(serialized) = self.storage_kind.get_item(&self.value_key(index))? else {
+                continue;
+            };
+            let value = self.codec.deserialize(serialized).map(|(value, _)| value).map_log_possible_error(|err| {
+                format!("Could not deserialize value {index} of log due to:\n{err}")
+            })?;
+            values.push(value);
+        }
+        for index in 0..len {
+            self.storage_kind.remove_item(&self.value_key(index))?;
+        }
+        self.storage_kind.remove_item(&self.counter_key())?;
+        Ok(values)
+    }
+}
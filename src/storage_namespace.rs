@@ -0,0 +1,47 @@
+use crate::{StorageData, StorageKind, DEFAULT_STORAGE_KIND};
+use alloc::format;
+use alloc::string::String;
+
+/// Group of keys sharing a prefix and a storage kind, allowing to build glues at runtime as tidy
+/// as those generated through the WebStorage derive macro and its ``Prepend_keys_with``.
+///
+/// ```rust no_run
+/// use storage_data::{StorageKind, StorageNamespace};
+///
+/// let settings = StorageNamespace::new("settings.").with_storage(StorageKind::Session);
+/// // The value lands in the Session Storage under the key settings.theme.
+/// let mut theme = settings.data("theme", || String::from("light"));
+/// theme.set(String::from("dark")).unwrap();
+/// ```
+pub struct StorageNamespace {
+    /// Prefix prepended to the key of every glue of this namespace.
+    pub prefix: String,
+    /// Storage kind targeted by every glue of this namespace.
+    pub kind: StorageKind,
+}
+
+impl StorageNamespace {
+    /// Creates a namespace whose glues' keys start by the indicated prefix, and which target the
+    /// default storage kind.
+    pub fn new(prefix: impl Into<String>) -> Self {
+        Self {
+            prefix: prefix.into(),
+            kind: DEFAULT_STORAGE_KIND,
+        }
+    }
+
+    /// Specifies the kind of storage every glue of this namespace targets to.
+    pub fn with_storage(mut self, kind: StorageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Creates a glue to the indicated key prefixed by this namespace's prefix, and targeting
+    /// this namespace's storage kind.
+    pub fn data<Value>(&self, key: &str, default: fn() -> Value) -> StorageData<String, Value>
+    where
+        Value: serde::Serialize + for<'de> serde::de::Deserialize<'de>,
+    {
+        StorageData::new(format!("{}{key}", self.prefix), default).with_storage(self.kind)
+    }
+}
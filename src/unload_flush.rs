@@ -0,0 +1,44 @@
+use crate::log_error::LogError;
+use alloc::boxed::Box;
+use alloc::format;
+use core::error::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+/// Invokes the indicated function right before the page is unloaded, such as when the user
+/// navigates away or closes the tab, which is meant to save values that were mutated but not
+/// saved yet, as glues kept in statics are never dropped, and the web browser might close the
+/// page without dropping any value.
+///
+/// As the function must be ``'static``, the values to save are usually shared with it through an
+/// [alloc::rc::Rc] and a [core::cell::RefCell]:
+///
+/// ```rust no_run
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+/// use storage_data::StorageData;
+///
+/// let volume = Rc::new(RefCell::new(StorageData::new("volume", || 100_u8)));
+/// let volume_to_flush = volume.clone();
+/// storage_data::install_beforeunload_flush(move || {
+///     let _ = volume_to_flush.borrow_mut().save();
+/// })
+/// .unwrap();
+///
+/// // Saved once the page is unloaded.
+/// **volume.borrow_mut() = 50;
+/// ```
+///
+/// The listener is kept for the whole life of the page, and installing it might fail if there is
+/// no ``window``, returning an explanation to this through an ``Err<Box<dyn Error>>``.
+pub fn install_beforeunload_flush<F: Fn() + 'static>(flush: F) -> Result<(), Box<dyn Error>> {
+    let window = crate::window().map_log_possible_error(|_| "Could not get windows")?;
+    let listener = Closure::<dyn Fn()>::new(flush);
+    window
+        .add_event_listener_with_callback("beforeunload", listener.as_ref().unchecked_ref())
+        .map_log_possible_error(|err| {
+            format!("Could not listen to beforeunload events due to {err:?}")
+        })?;
+    listener.forget();
+    Ok(())
+}
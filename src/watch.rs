@@ -0,0 +1,92 @@
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use futures_core::Stream;
+
+/// Channel shared by a [StorageWatch] and the glue sending values to it.
+pub(crate) type SharedChannel<Value> = Rc<RefCell<WatchChannel<Value>>>;
+
+/// Values a [StorageWatch] is yet to yield, shared with the glue sending them.
+pub(crate) struct WatchChannel<Value> {
+    values: VecDeque<Value>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+impl<Value> WatchChannel<Value> {
+    pub(crate) fn new() -> SharedChannel<Value> {
+        Rc::new(RefCell::new(Self {
+            values: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }))
+    }
+
+    /// Queues the value to be yielded, unless the channel is closed, waking the task polling the
+    /// stream, which is woken once the channel is no longer borrowed.
+    pub(crate) fn send(channel: &RefCell<Self>, value: Value) {
+        let waker = {
+            let mut channel = channel.borrow_mut();
+            if channel.closed {
+                return;
+            }
+            channel.values.push_back(value);
+            channel.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    /// Closes the channel, so the stream ends once every queued value is yielded.
+    pub(crate) fn close(channel: &RefCell<Self>) {
+        let waker = {
+            let mut channel = channel.borrow_mut();
+            channel.closed = true;
+            channel.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Stream of the values of a [crate::StorageData], yielding a new value every time it is
+/// persisted by the glue or changed from other tabs or windows, see [crate::StorageData::watch].
+///
+/// The stream ends once the glue is dropped, and values stop being queued once this is dropped.
+pub struct StorageWatch<Value> {
+    channel: SharedChannel<Value>,
+}
+
+impl<Value> StorageWatch<Value> {
+    pub(crate) fn new(channel: SharedChannel<Value>) -> Self {
+        Self { channel }
+    }
+}
+
+impl<Value> Stream for StorageWatch<Value> {
+    type Item = Value;
+
+    fn poll_next(self: Pin<&mut Self>, context: &mut Context<'_>) -> Poll<Option<Value>> {
+        let mut channel = self.channel.borrow_mut();
+        if let Some(value) = channel.values.pop_front() {
+            return Poll::Ready(Some(value));
+        }
+        if channel.closed {
+            return Poll::Ready(None);
+        }
+        channel.waker = Some(context.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// Upon drop, the channel is closed, so the glue stops queueing values for this stream.
+impl<Value> Drop for StorageWatch<Value> {
+    /// Upon drop, the channel is closed, so the glue stops queueing values for this stream.
+    fn drop(&mut self) {
+        WatchChannel::close(&self.channel);
+    }
+}
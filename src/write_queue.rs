@@ -0,0 +1,120 @@
+use crate::log_error::LogError;
+use crate::StorageKind;
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+use core::error::Error;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+
+std::thread_local! {
+    /// Serialized values waiting to be written, along with the storage and key to write them to,
+    /// in the order they were first enqueued.
+    static PENDING_WRITES: RefCell<Vec<(StorageKind, String, String)>> =
+        const { RefCell::new(Vec::new()) };
+    /// Tells whether a microtask flushing the pending writes is already scheduled.
+    static FLUSH_IS_SCHEDULED: Cell<bool> = const { Cell::new(false) };
+    /// Tells whether the pending writes are already flushed before the page is unloaded.
+    static UNLOAD_FLUSH_IS_INSTALLED: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Enqueues the write of the serialized value, replacing any pending write to the same key, and
+/// schedules the flush of every pending write for the end of the current task.
+pub(crate) fn enqueue(storage_kind: StorageKind, key: &str, serialized: String) {
+    PENDING_WRITES.with_borrow_mut(|pending_writes| {
+        match pending_writes
+            .iter_mut()
+            .find(|(pending_kind, pending_key, _)| {
+                *pending_kind == storage_kind && pending_key == key
+            })
+        {
+            Some((_, _, pending_serialized)) => *pending_serialized = serialized,
+            None => pending_writes.push((storage_kind, String::from(key), serialized)),
+        }
+    });
+    if !UNLOAD_FLUSH_IS_INSTALLED.replace(true) {
+        let installed = crate::install_beforeunload_flush(|| {
+            let _ = flush_pending();
+        });
+        if installed.is_err() {
+            UNLOAD_FLUSH_IS_INSTALLED.set(false);
+        }
+    }
+    if !FLUSH_IS_SCHEDULED.replace(true) && schedule_flush().is_err() {
+        FLUSH_IS_SCHEDULED.set(false);
+    }
+}
+
+/// Gets the serialized value waiting to be written to the key, if any.
+pub(crate) fn pending(storage_kind: &StorageKind, key: &str) -> Option<String> {
+    PENDING_WRITES.with_borrow(|pending_writes| {
+        pending_writes
+            .iter()
+            .find(|(pending_kind, pending_key, _)| {
+                pending_kind == storage_kind && pending_key == key
+            })
+            .map(|(_, _, serialized)| serialized.clone())
+    })
+}
+
+/// Discards the write waiting to be written to the key, if any, as when the key is removed.
+pub(crate) fn discard(storage_kind: &StorageKind, key: &str) {
+    PENDING_WRITES.with_borrow_mut(|pending_writes| {
+        pending_writes.retain(|(pending_kind, pending_key, _)| {
+            pending_kind != storage_kind || pending_key != key
+        })
+    });
+}
+
+fn schedule_flush() -> Result<(), Box<dyn Error>> {
+    let window = crate::window().map_log_possible_error(|_| "Could not get windows")?;
+    let on_microtask = Closure::once_into_js(|| {
+        let _ = flush_pending();
+    });
+    window.queue_microtask(on_microtask.unchecked_ref());
+    Ok(())
+}
+
+/// Writes every value waiting in the write queue right away, rather than waiting for the end of
+/// the current task, returning the keys whose values failed to be written, which are discarded
+/// rather than retried.
+///
+/// When the ``write_queue`` feature is enabled, [crate::StorageData::set],
+/// [crate::StorageData::save] and every other write through [StorageKind::try_set_item] are
+/// enqueued rather than written, replacing any write to the same key waiting in the queue, so
+/// glues changing many times in a single event handler take a single write each, which are
+/// flushed in a microtask once the current task ends, when a glue is dropped, before the page is
+/// unloaded, or when calling this function:
+///
+/// ```rust no_run
+/// use storage_data::StorageData;
+///
+/// let mut volume = StorageData::new("volume", || 100_u8);
+/// for volume_step in 0..10 {
+///     volume.set(volume_step * 10).unwrap();
+/// }
+///
+/// // Only the last value is written.
+/// storage_data::flush_pending().unwrap();
+/// ```
+///
+/// Reads through [StorageKind::get_item] see the values waiting in the queue, so glues read the
+/// values they set even before these are written, while failures writing them, such as the
+/// Storage being full, are only reported by this function, being those flushed automatically
+/// only logged.
+pub fn flush_pending() -> Result<(), Vec<String>> {
+    FLUSH_IS_SCHEDULED.set(false);
+    let pending_writes = PENDING_WRITES.take();
+    let failed_keys = pending_writes
+        .into_iter()
+        .filter_map(|(storage_kind, key, serialized)| {
+            storage_kind.write_item(&key, serialized).is_err().then_some(key)
+        })
+        .collect::<Vec<_>>();
+    if failed_keys.is_empty() {
+        Ok(())
+    } else {
+        Err(failed_keys)
+    }
+}
@@ -0,0 +1,19 @@
+#[test]
+fn derive_ui() {
+    let test_cases = trybuild::TestCases::new();
+    test_cases.pass("tests/ui/generic_struct.rs");
+    test_cases.pass("tests/ui/tuple_struct.rs");
+    test_cases.pass("tests/ui/builder.rs");
+    test_cases.pass("tests/ui/encapsulated_fields.rs");
+    test_cases.pass("tests/ui/flattened.rs");
+    test_cases.pass("tests/ui/expires_after.rs");
+    test_cases.pass("tests/ui/merge.rs");
+    test_cases.pass("tests/ui/key_case.rs");
+    #[cfg(feature = "use_serde_json")]
+    test_cases.pass("tests/ui/try_from_json.rs");
+    test_cases.compile_fail("tests/ui/lifetime_parameter.rs");
+    test_cases.compile_fail("tests/ui/enum.rs");
+    test_cases.compile_fail("tests/ui/constructor_visibility.rs");
+    test_cases.compile_fail("tests/ui/flattened_tuple.rs");
+    test_cases.compile_fail("tests/ui/expires_after_not_literal.rs");
+}
@@ -0,0 +1,260 @@
+#![cfg(feature = "default_serde_json")]
+
+use storage_data::__private::memory_storage_reads;
+use storage_data::{StorageData, StorageKind, StorageLog, StorageNamespace};
+
+#[test]
+fn memory_storage() {
+    let mut visited_times = StorageData::new("visited_times", || 0_usize);
+    visited_times.set(3).unwrap();
+    assert_eq!(
+        StorageKind::Local.get_item("visited_times").unwrap(),
+        Some(String::from("3"))
+    );
+    assert_eq!(StorageKind::Session.get_item("visited_times").unwrap(), None);
+
+    drop(visited_times);
+    let visited_times = StorageData::new("visited_times", || 0_usize);
+    assert_eq!(*visited_times, 3);
+
+    drop(visited_times);
+    let visited_times = StorageData::try_new("visited_times", || 0_usize).unwrap();
+    assert_eq!(*visited_times, 3);
+
+    let mut visited_times =
+        visited_times.reinterpret(|times| format!("{times} times"), || String::from("0 times"));
+    assert!(visited_times.is_dirty());
+    visited_times.save().unwrap();
+    assert_eq!(
+        StorageKind::Local.get_item("visited_times").unwrap(),
+        Some(String::from("\"3 times\""))
+    );
+
+    visited_times.remove().unwrap();
+    assert_eq!(StorageKind::Local.get_item("visited_times").unwrap(), None);
+}
+
+#[test]
+fn save_reads_storage_at_most_once() {
+    let mut volume = StorageData::new("mutated_volume", || 50_u8);
+    *volume += 1;
+    let reads = memory_storage_reads();
+    volume.save().unwrap();
+    assert_eq!(memory_storage_reads() - reads, 0);
+    drop(volume);
+
+    let mut volume = StorageData::new("volume_with_history", || 50_u8).with_history(1);
+    volume.save().unwrap();
+    drop(volume);
+    let mut volume = StorageData::new("volume_with_history", || 50_u8).with_history(1);
+    *volume += 1;
+    let reads = memory_storage_reads();
+    volume.save().unwrap();
+    assert_eq!(memory_storage_reads() - reads, 1);
+    assert_eq!(volume.history().unwrap(), [50]);
+    drop(volume);
+
+    let mut volume = StorageData::new("unmutated_volume", || 50_u8);
+    assert_eq!(*volume, 50);
+    let reads = memory_storage_reads();
+    volume.save().unwrap();
+    assert_eq!(memory_storage_reads() - reads, 1);
+    assert_eq!(
+        StorageKind::Local.get_item("unmutated_volume").unwrap(),
+        Some(String::from("50"))
+    );
+    drop(volume);
+
+    let mut volume = StorageData::new("unmutated_volume", || 50_u8);
+    assert_eq!(*volume, 50);
+    let reads = memory_storage_reads();
+    volume.save().unwrap();
+    assert_eq!(memory_storage_reads() - reads, 1);
+}
+
+#[test]
+fn display_or_falls_back_on_corruption() {
+    StorageKind::Local.set_item("theme", || Ok(String::from("not json"))).unwrap();
+    let theme = StorageData::new("theme", || String::from("light")).save_on_drop(false);
+    assert_eq!(theme.display_or("-"), "-");
+
+    let volume = StorageData::new("display_volume", || 50_u8);
+    assert_eq!(volume.display_or("-"), "50");
+}
+
+#[test]
+fn raw_bytes_round_trip() {
+    let every_byte = (0..=255).collect::<Vec<u8>>();
+    let mut bytes = StorageData::new("bytes", Vec::<u8>::new).raw_bytes();
+    bytes.set(every_byte.clone()).unwrap();
+    assert_eq!(
+        StorageKind::Local.get_item("bytes").unwrap().unwrap().chars().count(),
+        256
+    );
+
+    drop(bytes);
+    let bytes = StorageData::new("bytes", Vec::<u8>::new).raw_bytes();
+    assert_eq!(*bytes, every_byte);
+}
+
+#[test]
+fn serde_transparent() {
+    let language = StorageData::new("language", || String::from("en"));
+    assert_eq!(serde_json::to_string(&language).unwrap(), "\"en\"");
+
+    let mut language: StorageData<String, String> = serde_json::from_str("\"es\"").unwrap();
+    assert_eq!(*language, "es");
+    assert!(!language.is_dirty());
+    language.save().unwrap();
+    drop(language);
+    assert_eq!(StorageKind::Local.get_item("").unwrap(), None);
+
+    let language: StorageData<String, String> = serde_json::from_str("\"es\"").unwrap();
+    let language = language.with_key(String::from("deserialized_language"));
+    assert!(language.is_dirty());
+    drop(language);
+    assert_eq!(
+        StorageKind::Local.get_item("deserialized_language").unwrap(),
+        Some(String::from("\"es\""))
+    );
+}
+
+#[test]
+fn set_key_clears_cached_value() {
+    StorageKind::Local.set_item("first_name", || Ok(String::from("\"Ada\""))).unwrap();
+    let mut name = StorageData::new(String::from("first_name"), String::new);
+    *name = String::from("Grace");
+    name.set_key(String::from("last_name"));
+    assert!(!name.is_dirty());
+    assert_eq!(*name, "");
+
+    let name = name.with_key(String::from("first_name"));
+    assert_eq!(*name, "Ada");
+
+    let mut blank = StorageData::new(String::new(), String::new).save_on_drop(false);
+    *blank = String::from("Grace");
+    let blank = blank.with_key(String::from("blank_rebound"));
+    assert!(!blank.is_dirty());
+    drop(blank);
+    assert_eq!(StorageKind::Local.get_item("blank_rebound").unwrap(), None);
+}
+
+#[cfg(feature = "compress")]
+#[test]
+fn compressed_values_are_shorter() {
+    let samples = (0..35_000).map(|sample| sample % 100).collect::<Vec<u32>>();
+    let uncompressed_len = serde_json::to_string(&samples).unwrap().len();
+    assert!(uncompressed_len > 90_000);
+    let mut compressed_samples =
+        StorageData::new("compressed_samples", Vec::<u32>::new).compressed();
+    compressed_samples.set(samples.clone()).unwrap();
+    let stored = StorageKind::Local.get_item("compressed_samples").unwrap().unwrap();
+    assert!(stored.len() < uncompressed_len / 10);
+
+    drop(compressed_samples);
+    let compressed_samples = StorageData::new("compressed_samples", Vec::<u32>::new).compressed();
+    assert_eq!(*compressed_samples, samples);
+
+    StorageKind::Local.set_item("uncompressed_samples", || Ok(String::from("[1,2,3]"))).unwrap();
+    let samples = StorageData::new("uncompressed_samples", Vec::<u32>::new).compressed();
+    assert_eq!(*samples, [1, 2, 3]);
+}
+
+#[test]
+fn storage_log_drains_in_append_order() {
+    let clicks = StorageLog::new("clicks");
+    let other_clicks = StorageLog::new("clicks");
+    clicks.append(String::from("play")).unwrap();
+    other_clicks.append(String::from("pause")).unwrap();
+    clicks.append(String::from("stop")).unwrap();
+    assert_eq!(other_clicks.len().unwrap(), 3);
+
+    assert_eq!(clicks.drain().unwrap(), ["play", "pause", "stop"]);
+    for key in ["clicks.0", "clicks.1", "clicks.2", "clicks.next"] {
+        assert_eq!(StorageKind::Local.get_item(key).unwrap(), None);
+    }
+    assert!(other_clicks.is_empty().unwrap());
+}
+
+#[test]
+fn take_keeps_the_key_while_take_and_remove_removes_it() {
+    let mut pending_action = StorageData::new("pending_action", || None::<String>);
+    *pending_action = Some(String::from("checkout"));
+    assert_eq!(pending_action.take(), Some(String::from("checkout")));
+    assert_eq!(
+        StorageKind::Local.get_item("pending_action").unwrap(),
+        Some(String::from("\"checkout\""))
+    );
+
+    let pending_action = StorageData::new("pending_action", || None::<String>);
+    assert_eq!(pending_action.take_and_remove(), Some(String::from("checkout")));
+    assert_eq!(StorageKind::Local.get_item("pending_action").unwrap(), None);
+}
+
+#[test]
+fn namespace_prefixes_keys() {
+    let settings = StorageNamespace::new("settings.").with_storage(StorageKind::Session);
+    let mut theme = settings.data("theme", || String::from("light"));
+    theme.set(String::from("dark")).unwrap();
+    assert_eq!(
+        StorageKind::Session.get_item("settings.theme").unwrap(),
+        Some(String::from("\"dark\""))
+    );
+    assert_eq!(StorageKind::Local.get_item("settings.theme").unwrap(), None);
+}
+
+#[test]
+fn raw_string_is_stored_without_quoting() {
+    let mut name = StorageData::new("raw_name", String::new).raw_string();
+    name.set(String::from("Jorge")).unwrap();
+    assert_eq!(
+        StorageKind::Local.get_item("raw_name").unwrap(),
+        Some(String::from("Jorge"))
+    );
+
+    drop(name);
+    let name = StorageData::new("raw_name", String::new).raw_string();
+    assert_eq!(*name, "Jorge");
+}
+
+#[cfg(feature = "use_serde_bincode")]
+#[test]
+fn binary_as_latin1_round_trip() {
+    let every_byte = (0..=255).collect::<Vec<u8>>();
+    let mut bytes = StorageData::new("latin1_bytes", Vec::<u8>::new)
+        .serde_bincode()
+        .binary_as_latin1();
+    bytes.set(every_byte.clone()).unwrap();
+    let stored = StorageKind::Local.get_item("latin1_bytes").unwrap().unwrap();
+    assert!(stored.chars().all(|character| character <= '\u{FF}'));
+
+    drop(bytes);
+    let bytes = StorageData::new("latin1_bytes", Vec::<u8>::new)
+        .serde_bincode()
+        .binary_as_latin1();
+    assert_eq!(*bytes, every_byte);
+}
+
+#[cfg(feature = "use_serde_bincode")]
+#[test]
+#[should_panic(expected = "binary_as_latin1 requires the serde format to be bincode's or CBOR's")]
+fn binary_as_latin1_rejects_json() {
+    let _ = StorageData::new("latin1_json", Vec::<u8>::new).binary_as_latin1();
+}
+
+#[test]
+fn undo_discards_deferred_writes() {
+    let mut draft = StorageData::new("undo_draft", String::new)
+        .with_history(1)
+        .debounce_save(50.0);
+    draft.set(String::from("first")).unwrap();
+    draft.set(String::from("second")).unwrap();
+    *draft = String::from("third");
+    assert!(draft.undo().unwrap());
+    assert_eq!(*draft, "first");
+    drop(draft);
+    assert_eq!(
+        StorageKind::Local.get_item("undo_draft").unwrap(),
+        Some(String::from("\"first\""))
+    );
+}
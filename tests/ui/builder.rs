@@ -0,0 +1,23 @@
+use storage_data::derive_web_storage::WebStorage;
+use storage_data::StorageKind;
+
+#[WebStorage(Prepend_keys_with(BUILT::), DeriveBuilder)]
+pub struct Storage {
+    visited_times: usize,
+    name: Option<String>,
+}
+
+#[WebStorage(DeriveBuilder(CountersBuilder))]
+pub struct Counters<T: Default>(T, T);
+
+fn main() {
+    let storage = Storage::builder()
+        .with_visited_times_storage(StorageKind::Session)
+        .with_visited_times_default(|| 3)
+        .build();
+    assert_eq!(*storage.visited_times, 3);
+    assert_eq!(storage.visited_times.storage_kind(), &StorageKind::Session);
+    assert_eq!(*storage.name, None);
+
+    let _ = Counters::<u8>::builder().with_field_1_default(|| 1).build();
+}
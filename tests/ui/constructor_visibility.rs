@@ -0,0 +1,8 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage(ConstructorVisibility(123))]
+pub struct Storage {
+    visited_times: usize,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+mod settings {
+    use storage_data::derive_web_storage::WebStorage;
+
+    #[WebStorage(Prepend_keys_with(ENCAPSULATED::), EncapsulateFields, DeriveSnapshot)]
+    pub struct Settings {
+        volume: u8,
+        theme: Option<String>,
+    }
+
+    #[WebStorage(EncapsulateFields)]
+    pub struct Counters(u8, u8);
+}
+
+use settings::{Counters, Settings};
+
+fn main() {
+    let mut settings = Settings::new();
+    **settings.volume_mut() = 50;
+    assert_eq!(**settings.volume(), 50);
+    assert!(!settings.theme_is_present());
+    let _ = settings.snapshot();
+
+    let mut counters = Counters::new();
+    **counters.field_1_mut() += 1;
+    assert_eq!(**counters.field_1(), 1);
+}
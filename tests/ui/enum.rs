@@ -0,0 +1,8 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage]
+pub enum Storage {
+    Visited(usize),
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage]
+pub struct Auth {
+    #[ExpiresAfter(3600000)]
+    last_login_hint: Option<String>,
+    #[ExpiresAfter(1.5e3)]
+    session_hint: Option<String>,
+    remember_me: bool,
+}
+
+fn main() {
+    let mut auth = Auth::new();
+    auth.last_login_hint.set(Some(String::from("jorge"))).unwrap();
+    assert_eq!(*auth.last_login_hint, Some(String::from("jorge")));
+    *auth.remember_me = true;
+    let _ = auth.session_hint_is_present();
+}
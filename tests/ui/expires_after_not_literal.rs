@@ -0,0 +1,11 @@
+use storage_data::derive_web_storage::WebStorage;
+
+const HOUR_MS: u64 = 3600000;
+
+#[WebStorage]
+pub struct Auth {
+    #[ExpiresAfter(HOUR_MS)]
+    last_login_hint: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+use storage_data::derive_web_storage::WebStorage;
+use storage_data::StorageKind;
+
+#[WebStorage(Prepend_keys_with(VIDEO::))]
+pub struct VideoSettings {
+    resolution: u32,
+}
+
+#[WebStorage(Prepend_keys_with(SETTINGS::))]
+pub struct Settings {
+    #[Flatten]
+    video: VideoSettings,
+    /// Volume of every sound, from 0 to 100.
+    volume: u8,
+}
+
+fn main() {
+    let mut settings = Settings::new_with_prefix("42::");
+    *settings.video.resolution = 1080;
+    assert!(settings.is_dirty());
+    settings.save(true).unwrap();
+    assert!(StorageKind::Local.get_item("42::SETTINGS::VIDEO::resolution").unwrap().is_some());
+    settings.reset_all().unwrap();
+    assert_eq!(StorageKind::Local.get_item("42::SETTINGS::VIDEO::resolution").unwrap(), None);
+    assert_eq!(settings.len(), 1);
+    assert_eq!(
+        settings.describe(),
+        [("SETTINGS::volume", "Volume of every sound, from 0 to 100.")]
+    );
+}
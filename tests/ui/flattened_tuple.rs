@@ -0,0 +1,11 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage]
+pub struct VideoSettings {
+    resolution: u32,
+}
+
+#[WebStorage]
+pub struct Settings(#[Flatten] VideoSettings, u8);
+
+fn main() {}
@@ -0,0 +1,18 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage(Prepend_keys_with(GENERIC::), DeriveSnapshot)]
+pub struct Storage<T: Default + Clone, U>
+where
+    U: Default + Clone,
+{
+    value: T,
+    values: Vec<U>,
+}
+
+#[WebStorage]
+pub struct Counters<T: Default>(T, T);
+
+fn main() {
+    let _ = Storage::<u32, String>::new;
+    let _ = Counters::<u8>::new;
+}
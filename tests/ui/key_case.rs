@@ -0,0 +1,25 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage(KeyCase(Verbatim))]
+pub struct Verbatim {
+    visited_times: usize,
+}
+
+#[WebStorage(KeyCase(Kebab))]
+pub struct Kebab {
+    visited_times: usize,
+}
+
+#[WebStorage(KeyCase(Camel))]
+pub struct Camel {
+    visited_times: usize,
+}
+
+const _: () = assert!(Kebab::KEYS.len() == 1);
+
+fn main() {
+    assert_eq!(Verbatim::new().web_keys(), ["visited_times"]);
+    assert_eq!(Kebab::new().web_keys(), ["visited-times"]);
+    assert_eq!(Camel::new().web_keys(), ["visitedTimes"]);
+    assert_eq!(Camel::KEYS, ["visitedTimes"]);
+}
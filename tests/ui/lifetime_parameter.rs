@@ -0,0 +1,9 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage]
+pub struct Storage<'a> {
+    value: u32,
+    name: core::marker::PhantomData<&'a str>,
+}
+
+fn main() {}
@@ -0,0 +1,45 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage(Prepend_keys_with(VIDEO::), DeriveMerge)]
+pub struct VideoSettings {
+    resolution: u32,
+}
+
+#[WebStorage(Prepend_keys_with(SETTINGS::), DeriveMerge)]
+pub struct Settings {
+    #[Flatten]
+    video: VideoSettings,
+    volume: u8,
+    theme: Option<String>,
+}
+
+#[WebStorage(DeriveMerge)]
+pub struct Counters(u8, u8);
+
+fn main() {
+    let mut preset = Settings::new_with_prefix("preset::");
+    *preset.video.resolution = 720;
+    *preset.volume = 20;
+
+    let mut settings = Settings::new();
+    *settings.theme = Some(String::from("dark"));
+    settings.merge_from(&preset);
+    assert_eq!(*settings.video.resolution, 720);
+    assert_eq!(*settings.volume, 20);
+    assert_eq!(*settings.theme, None);
+    assert!(settings.is_dirty());
+
+    *settings.theme = Some(String::from("dark"));
+    *preset.volume = 0;
+    *preset.video.resolution = 1080;
+    settings.merge_changed_only(&preset);
+    assert_eq!(*settings.video.resolution, 1080);
+    assert_eq!(*settings.volume, 20);
+    assert_eq!(*settings.theme, Some(String::from("dark")));
+    settings.reset_all().unwrap();
+    preset.reset_all().unwrap();
+
+    let mut counters = Counters::new();
+    counters.merge_from(&Counters::new());
+    assert_eq!(*counters.0, 0);
+}
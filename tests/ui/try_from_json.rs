@@ -0,0 +1,16 @@
+use storage_data::derive_web_storage::WebStorage;
+use storage_data::StorageError;
+
+#[WebStorage(Prepend_keys_with(JSON::))]
+pub struct Storage<T: Default + Clone> {
+    value: T,
+}
+
+fn main() {
+    let storage = Storage::<u32>::try_from(r#"{"JSON::value": 3}"#).unwrap();
+    assert_eq!(*storage.value, 3);
+    let corrupt = Storage::<u32>::try_from(r#"{"JSON::value": "three"}"#);
+    assert!(matches!(corrupt, Err(StorageError::SerdeDeserialize(_))));
+    let malformed = Storage::<u32>::try_from("{");
+    assert!(matches!(malformed, Err(StorageError::SerdeDeserialize(_))));
+}
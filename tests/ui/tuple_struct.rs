@@ -0,0 +1,9 @@
+use storage_data::derive_web_storage::WebStorage;
+
+#[WebStorage(Prepend_keys_with(COUNTERS::))]
+pub struct Counters(usize, Vec<String>);
+
+fn main() {
+    let _ = Counters::new;
+    let _ = Counters::field_0;
+}